@@ -0,0 +1,224 @@
+//! Double-ended queue implementation providing access to both ends.
+//!
+//! Unlike `Stack` and `Queue`, which only expose a single access pattern,
+//! a `Deque` lets elements be added and removed at either end. This makes it
+//! usable as a stack, a queue, or a mix of both.
+//!
+//! The implementation uses a linked list as its underlying storage mechanism,
+//! providing O(1) operations at both ends.
+
+use crate::list::linked_list::List;
+use std::fmt::Debug;
+
+/// A double-ended queue.
+///
+/// `Deque<T>` stores elements in order and allows pushing and popping at both
+/// the front and the back. This implementation uses a linked list as its
+/// underlying storage, providing O(1) complexity for every push and pop.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of elements stored in the deque.
+///
+/// # Examples
+///
+/// ```
+/// use raw_double_linked_list::prelude::Deque;
+///
+/// let mut deque = Deque::new();
+///
+/// // Add elements at both ends
+/// deque.push_back(2);
+/// deque.push_front(1);
+/// deque.push_back(3);
+///
+/// assert_eq!(deque.front(), Some(&1));
+/// assert_eq!(deque.back(), Some(&3));
+///
+/// // Remove elements from both ends
+/// assert_eq!(deque.pop_front(), Some(1));
+/// assert_eq!(deque.pop_back(), Some(3));
+/// assert_eq!(deque.pop_back(), Some(2));
+/// assert_eq!(deque.pop_front(), None);
+/// ```
+#[derive(Default)]
+pub struct Deque<T> {
+    inner: List<T>,
+}
+
+impl<T> Deque<T> {
+    /// Creates a new, empty deque.
+    ///
+    /// # Returns
+    ///
+    /// A new `Deque<T>` with no elements.
+    pub fn new() -> Self {
+        Self { inner: List::new() }
+    }
+
+    /// Adds an element to the front of the deque.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The element to add
+    pub fn push_front(&mut self, value: T) {
+        self.inner.push_front(value);
+    }
+
+    /// Adds an element to the back of the deque.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The element to add
+    pub fn push_back(&mut self, value: T) {
+        self.inner.push_back(value);
+    }
+
+    /// Removes and returns the element at the front of the deque.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` - If the deque contains at least one element
+    /// * `None` - If the deque is empty
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.inner.pop_front()
+    }
+
+    /// Removes and returns the element at the back of the deque.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` - If the deque contains at least one element
+    /// * `None` - If the deque is empty
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.inner.pop_back()
+    }
+
+    /// Returns a reference to the element at the front of the deque.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` - If the deque contains at least one element
+    /// * `None` - If the deque is empty
+    pub fn front(&self) -> Option<&T> {
+        self.inner.first()
+    }
+
+    /// Returns a reference to the element at the back of the deque.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` - If the deque contains at least one element
+    /// * `None` - If the deque is empty
+    pub fn back(&self) -> Option<&T> {
+        self.inner.last()
+    }
+
+    /// Returns the current number of elements in the deque.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements currently stored in the deque
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Checks if the deque is empty.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the deque contains no elements, `false` otherwise
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl<T: Debug> Debug for Deque<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.inner.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basic_deque_test() {
+        // Create a new deque
+        let mut deque = Deque::<i32>::new();
+
+        // Test that a new deque is empty
+        assert_eq!(deque.len(), 0);
+        assert!(deque.is_empty());
+        assert_eq!(deque.front(), None);
+        assert_eq!(deque.back(), None);
+
+        // Test push operations at both ends
+        deque.push_back(2);
+        deque.push_front(1);
+        deque.push_back(3);
+        assert_eq!(deque.len(), 3);
+        assert_eq!(deque.front(), Some(&1));
+        assert_eq!(deque.back(), Some(&3));
+
+        // Test pop operations at both ends
+        assert_eq!(deque.pop_front(), Some(1));
+        assert_eq!(deque.pop_back(), Some(3));
+        assert_eq!(deque.pop_front(), Some(2));
+        assert_eq!(deque.len(), 0);
+
+        // Test popping from an empty deque
+        assert_eq!(deque.pop_front(), None);
+        assert_eq!(deque.pop_back(), None);
+    }
+
+    #[test]
+    fn test_fifo_from_front() {
+        // Pushing at the back and popping at the front is a queue
+        let mut deque = Deque::new();
+        deque.push_back(1);
+        deque.push_back(2);
+        assert_eq!(deque.pop_front(), Some(1));
+        deque.push_back(3);
+        assert_eq!(deque.pop_front(), Some(2));
+        assert_eq!(deque.pop_front(), Some(3));
+        assert!(deque.is_empty());
+    }
+
+    #[test]
+    fn test_lifo_from_back() {
+        // Pushing and popping at the back is a stack
+        let mut deque = Deque::new();
+        deque.push_back(1);
+        deque.push_back(2);
+        assert_eq!(deque.pop_back(), Some(2));
+        deque.push_back(3);
+        deque.push_front(0);
+        assert_eq!(deque.pop_back(), Some(3));
+        assert_eq!(deque.pop_back(), Some(1));
+        assert_eq!(deque.pop_back(), Some(0));
+        assert!(deque.is_empty());
+    }
+
+    #[test]
+    fn test_default() {
+        // Test the Default implementation
+        let deque: Deque<i32> = Deque::default();
+        assert_eq!(deque.len(), 0);
+    }
+
+    #[test]
+    fn test_debug_implementation() {
+        let mut deque = Deque::<i32>::new();
+
+        // Test Debug formatting on empty deque
+        assert_eq!(format!("{:?}", deque), "[]");
+
+        // Test Debug formatting with elements, front first
+        deque.push_back(2);
+        deque.push_front(1);
+        deque.push_back(3);
+        assert_eq!(format!("{:?}", deque), "[1, 2, 3]");
+    }
+}
@@ -1,3 +1,5 @@
+pub mod deque;
 pub mod single_ended_collection;
 pub mod queue;
 pub mod stack;
+pub mod view;
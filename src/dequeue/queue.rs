@@ -9,11 +9,14 @@
 
 use super::single_ended_collection::SingleEndedCollection ;
 use crate::list::linked_list::List;
+use crate::list::list_into_iter::IntoIter;
+use crate::list::list_iter::ListIter;
 use std::fmt::Debug;
 
 #[derive(Default)]
 pub struct Queue<T> {
     inner: List<T>,
+    capacity: Option<usize>,
 }
 
 /// A first-in, first-out (FIFO) collection.
@@ -48,7 +51,70 @@ impl<T> Queue<T> {
     ///
     /// A new `Queue<T>` with no elements.
     pub fn new() -> Self {
-        Self { inner: List::new() }
+        Self {
+            inner: List::new(),
+            capacity: None,
+        }
+    }
+
+    /// Creates a new, empty queue bounded to `cap` elements.
+    ///
+    /// The capacity is only enforced by `try_push`, `push` stays unbounded.
+    ///
+    /// # Parameters
+    ///
+    /// * `cap` - The maximum number of elements `try_push` accepts
+    ///
+    /// # Returns
+    ///
+    /// A new `Queue<T>` with no elements.
+    pub fn with_capacity(cap: usize) -> Self {
+        Self {
+            inner: List::new(),
+            capacity: Some(cap),
+        }
+    }
+
+    /// Returns the capacity of the queue.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(usize)` - If the queue was created with `with_capacity`
+    /// * `None` - If the queue is unbounded
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    /// Adds an element to the queue unless it is full.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The element to add to the queue
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the element was added
+    /// * `Err(T)` - If the queue is at capacity, handing the value back
+    pub fn try_push(&mut self, value: T) -> Result<(), T> {
+        match self.capacity {
+            Some(cap) if self.inner.len() >= cap => Err(value),
+            _ => {
+                self.inner.push_front(value);
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns an iterator over the elements, in pop order.
+    ///
+    /// The elements are yielded in the order repeated calls to `pop` would
+    /// return them, without removing anything.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over references to the elements
+    pub fn iter(&self) -> std::iter::Rev<ListIter<'_, T>> {
+        self.inner.iter().rev()
     }
 }
 
@@ -59,6 +125,14 @@ impl<T> SingleEndedCollection  for Queue<T> {
         self.inner.pop_back()
     }
 
+    fn peek(&self) -> Option<&Self::Item> {
+        self.inner.last()
+    }
+
+    fn peek_mut(&mut self) -> Option<&mut Self::Item> {
+        self.inner.last_mut()
+    }
+
     fn push(&mut self, value: Self::Item) {
         self.inner.push_front(value);
     }
@@ -68,12 +142,62 @@ impl<T> SingleEndedCollection  for Queue<T> {
     }
 }
 
+/// Builds a queue by pushing the items in iteration order.
+///
+/// As with repeated calls to `push`, the first item is popped first: collecting
+/// `[1, 2, 3]` pops `1`, `2`, then `3`.
+impl<T> FromIterator<T> for Queue<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut queue = Self::new();
+        for value in iter {
+            queue.push(value);
+        }
+        queue
+    }
+}
+
+/// Consumes the queue into an iterator yielding the elements in pop order.
+impl<T> IntoIterator for Queue<T> {
+    type Item = T;
+    type IntoIter = std::iter::Rev<IntoIter<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_rev_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Queue<T> {
+    type Item = &'a T;
+    type IntoIter = std::iter::Rev<ListIter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 impl<T: Debug> Debug for Queue<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_list().entries(self.inner.iter().rev()).finish()
     }
 }
 
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Queue<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.inner.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Queue<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        List::deserialize(deserializer).map(|inner| Self {
+            inner,
+            capacity: None,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -154,4 +278,115 @@ mod tests {
         assert_eq!(queue.pop(), Some("second".to_string()));
         assert_eq!(queue.pop(), Some("third".to_string()));
     }
+
+    #[test]
+    fn test_peek() {
+        let mut queue = Queue::<i32>::new();
+
+        // Peeking an empty queue returns nothing
+        assert_eq!(queue.peek(), None);
+
+        queue.push(1);
+        queue.push(2);
+        queue.push(3);
+
+        // Peek returns what pop returns next, without removing it
+        while let Some(&peeked) = queue.peek() {
+            let len = queue.len();
+            assert_eq!(queue.pop(), Some(peeked));
+            assert_eq!(queue.len(), len - 1);
+        }
+        assert_eq!(queue.peek(), None);
+    }
+
+    #[test]
+    fn test_peek_mut() {
+        let mut queue = Queue::<i32>::new();
+
+        // Peeking an empty queue returns nothing
+        assert_eq!(queue.peek_mut(), None);
+
+        queue.push(1);
+        queue.push(2);
+        queue.push(3);
+
+        // Mutate the next element in place
+        if let Some(next) = queue.peek_mut() {
+            *next += 10;
+        }
+
+        // The change persisted and nothing was removed
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.pop(), Some(1 + 10));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let mut queue = Queue::new();
+        queue.push(1);
+        queue.push(2);
+        queue.push(3);
+
+        // The round trip keeps the pop order
+        let json = serde_json::to_string(&queue).unwrap();
+        let mut decoded: Queue<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.len(), 3);
+        while let Some(value) = queue.pop() {
+            assert_eq!(decoded.pop(), Some(value));
+        }
+        assert!(decoded.is_empty());
+    }
+
+    #[test]
+    fn test_bounded_try_push() {
+        let mut queue = Queue::with_capacity(2);
+        assert_eq!(queue.capacity(), Some(2));
+
+        // Fill the queue up to its capacity
+        assert_eq!(queue.try_push(1), Ok(()));
+        assert_eq!(queue.try_push(2), Ok(()));
+
+        // A full queue hands the value back
+        assert_eq!(queue.try_push(3), Err(3));
+        assert_eq!(queue.len(), 2);
+
+        // Popping frees a slot
+        assert_eq!(queue.pop(), Some(1));
+        assert_eq!(queue.try_push(3), Ok(()));
+        assert_eq!(queue.pop(), Some(2));
+        assert_eq!(queue.pop(), Some(3));
+
+        // An unbounded queue accepts everything
+        let mut unbounded = Queue::new();
+        assert_eq!(unbounded.capacity(), None);
+        assert!((0..100).all(|i| unbounded.try_push(i).is_ok()));
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let mut queue = [1, 2, 3].into_iter().collect::<Queue<_>>();
+        assert_eq!(queue.len(), 3);
+
+        // Pops follow the queue order of the pushed items
+        let popped = std::iter::from_fn(|| queue.pop()).collect::<Vec<_>>();
+        assert_eq!(popped, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_iter_matches_pop_order() {
+        let mut queue = (1..=4).collect::<Queue<_>>();
+
+        // Borrowing and consuming iterators follow the pop order
+        let borrowed = queue.iter().copied().collect::<Vec<_>>();
+        let by_ref = (&queue).into_iter().copied().collect::<Vec<_>>();
+        let consumed = (1..=4)
+            .collect::<Queue<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        let popped = std::iter::from_fn(|| queue.pop()).collect::<Vec<_>>();
+        assert_eq!(borrowed, popped);
+        assert_eq!(by_ref, popped);
+        assert_eq!(consumed, popped);
+    }
 }
@@ -21,6 +21,27 @@ pub trait SingleEndedCollection  {
     /// * `None` - If the data structure is empty
     fn pop(&mut self) -> Option<Self::Item>;
 
+    /// Returns a reference to the next element without removing it.
+    ///
+    /// This is the element a subsequent call to `pop` would return.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&Item)` - If the data structure contains at least one element
+    /// * `None` - If the data structure is empty
+    fn peek(&self) -> Option<&Self::Item>;
+
+    /// Returns a mutable reference to the next element without removing it.
+    ///
+    /// This is the element a subsequent call to `pop` would return, it can be
+    /// updated in place instead of being popped and pushed back.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&mut Item)` - If the data structure contains at least one element
+    /// * `None` - If the data structure is empty
+    fn peek_mut(&mut self) -> Option<&mut Self::Item>;
+
     /// Adds a new element to the data structure.
     ///
     /// The specific behavior (where the element is added) depends on the implementation.
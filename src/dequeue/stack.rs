@@ -6,6 +6,8 @@
 
 use super::single_ended_collection::SingleEndedCollection ;
 use crate::list::linked_list::List;
+use crate::list::list_into_iter::IntoIter;
+use crate::list::list_iter::ListIter;
 use std::fmt::Debug;
 
 /// A last-in, first-out (LIFO) collection.
@@ -48,6 +50,18 @@ impl<T> Stack<T> {
     pub fn new() -> Self {
         Self { inner: List::new() }
     }
+
+    /// Returns an iterator over the elements, in pop order.
+    ///
+    /// The elements are yielded in the order repeated calls to `pop` would
+    /// return them, without removing anything.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over references to the elements
+    pub fn iter(&self) -> std::iter::Rev<ListIter<'_, T>> {
+        self.inner.iter().rev()
+    }
 }
 
 impl<T> SingleEndedCollection  for Stack<T> {
@@ -57,6 +71,14 @@ impl<T> SingleEndedCollection  for Stack<T> {
         self.inner.pop_back()
     }
 
+    fn peek(&self) -> Option<&Self::Item> {
+        self.inner.last()
+    }
+
+    fn peek_mut(&mut self) -> Option<&mut Self::Item> {
+        self.inner.last_mut()
+    }
+
     fn push(&mut self, value: Self::Item) {
         self.inner.push_back(value);
     }
@@ -66,12 +88,59 @@ impl<T> SingleEndedCollection  for Stack<T> {
     }
 }
 
+/// Builds a stack by pushing the items in iteration order.
+///
+/// As with repeated calls to `push`, the last item is popped first: collecting
+/// `[1, 2, 3]` pops `3`, `2`, then `1`.
+impl<T> FromIterator<T> for Stack<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut stack = Self::new();
+        for value in iter {
+            stack.push(value);
+        }
+        stack
+    }
+}
+
+/// Consumes the stack into an iterator yielding the elements in pop order.
+impl<T> IntoIterator for Stack<T> {
+    type Item = T;
+    type IntoIter = std::iter::Rev<IntoIter<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_rev_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Stack<T> {
+    type Item = &'a T;
+    type IntoIter = std::iter::Rev<ListIter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 impl<T: Debug> Debug for Stack<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_list().entries(self.inner.iter()).finish()
     }
 }
 
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Stack<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.inner.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Stack<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        List::deserialize(deserializer).map(|inner| Self { inner })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -138,4 +207,90 @@ mod tests {
         let debug_filled = format!("{:?}", stack);
         assert_eq!(debug_filled, "[1, 2, 3]");
     }
+
+    #[test]
+    fn test_peek() {
+        let mut stack = Stack::<i32>::new();
+
+        // Peeking an empty stack returns nothing
+        assert_eq!(stack.peek(), None);
+
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+
+        // Peek returns what pop returns next, without removing it
+        while let Some(&peeked) = stack.peek() {
+            let len = stack.len();
+            assert_eq!(stack.pop(), Some(peeked));
+            assert_eq!(stack.len(), len - 1);
+        }
+        assert_eq!(stack.peek(), None);
+    }
+
+    #[test]
+    fn test_peek_mut() {
+        let mut stack = Stack::<i32>::new();
+
+        // Peeking an empty stack returns nothing
+        assert_eq!(stack.peek_mut(), None);
+
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+
+        // Mutate the next element in place
+        if let Some(next) = stack.peek_mut() {
+            *next += 10;
+        }
+
+        // The change persisted and nothing was removed
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.pop(), Some(3 + 10));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let mut stack = Stack::new();
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+
+        // The round trip keeps the pop order
+        let json = serde_json::to_string(&stack).unwrap();
+        let mut decoded: Stack<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.len(), 3);
+        while let Some(value) = stack.pop() {
+            assert_eq!(decoded.pop(), Some(value));
+        }
+        assert!(decoded.is_empty());
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let mut stack = [1, 2, 3].into_iter().collect::<Stack<_>>();
+        assert_eq!(stack.len(), 3);
+
+        // Pops follow the stack order of the pushed items
+        let popped = std::iter::from_fn(|| stack.pop()).collect::<Vec<_>>();
+        assert_eq!(popped, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn test_iter_matches_pop_order() {
+        let mut stack = (1..=4).collect::<Stack<_>>();
+
+        // Borrowing and consuming iterators follow the pop order
+        let borrowed = stack.iter().copied().collect::<Vec<_>>();
+        let by_ref = (&stack).into_iter().copied().collect::<Vec<_>>();
+        let consumed = (1..=4)
+            .collect::<Stack<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        let popped = std::iter::from_fn(|| stack.pop()).collect::<Vec<_>>();
+        assert_eq!(borrowed, popped);
+        assert_eq!(by_ref, popped);
+        assert_eq!(consumed, popped);
+    }
 }
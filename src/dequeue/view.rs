@@ -0,0 +1,197 @@
+//! Stack and queue views over a borrowed list.
+//!
+//! A view borrows an existing `List` mutably and exposes it through the
+//! `SingleEndedCollection` interface, with the same semantics as `Stack` and
+//! `Queue`. Every operation is applied directly to the borrowed list, so the
+//! list doesn't have to be moved into a collection and taken back out.
+//!
+//! Views are created with `List::as_stack_mut` and `List::as_queue_mut`,
+//! which are defined here so the list module doesn't depend on this one.
+
+use super::single_ended_collection::SingleEndedCollection;
+use crate::list::linked_list::List;
+
+/// A last-in, first-out (LIFO) view over a borrowed list.
+///
+/// Elements are pushed to and popped from the back of the list.
+///
+/// # Examples
+///
+/// ```
+/// # use raw_double_linked_list::prelude::{List, SingleEndedCollection};
+/// let mut list = List::from([1, 2]);
+///
+/// let mut stack = list.as_stack_mut();
+/// stack.push(3);
+/// assert_eq!(stack.pop(), Some(3));
+/// assert_eq!(stack.pop(), Some(2));
+///
+/// assert_eq!(list, List::from([1]));
+/// ```
+pub struct StackView<'a, T> {
+    inner: &'a mut List<T>,
+}
+
+impl<'a, T> StackView<'a, T> {
+    /// Borrows a list as a stack.
+    ///
+    /// The view pushes and pops at the back of the list.
+    ///
+    /// # Parameters
+    ///
+    /// * `inner` - The list the view operates on
+    pub fn new(inner: &'a mut List<T>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T> SingleEndedCollection for StackView<'_, T> {
+    type Item = T;
+
+    fn pop(&mut self) -> Option<Self::Item> {
+        self.inner.pop_back()
+    }
+
+    fn peek(&self) -> Option<&Self::Item> {
+        self.inner.last()
+    }
+
+    fn peek_mut(&mut self) -> Option<&mut Self::Item> {
+        self.inner.last_mut()
+    }
+
+    fn push(&mut self, value: Self::Item) {
+        self.inner.push_back(value);
+    }
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+/// A first-in, first-out (FIFO) view over a borrowed list.
+///
+/// Like `Queue`, elements are pushed to the front of the list and popped from
+/// its back, so the back of the list is the oldest element.
+///
+/// # Examples
+///
+/// ```
+/// # use raw_double_linked_list::prelude::{List, SingleEndedCollection};
+/// let mut list = List::new();
+///
+/// let mut queue = list.as_queue_mut();
+/// queue.push(1);
+/// queue.push(2);
+/// assert_eq!(queue.pop(), Some(1));
+///
+/// assert_eq!(list, List::from([2]));
+/// ```
+pub struct QueueView<'a, T> {
+    inner: &'a mut List<T>,
+}
+
+impl<'a, T> QueueView<'a, T> {
+    /// Borrows a list as a queue.
+    ///
+    /// Like `Queue`, the view pushes at the front of the list and pops at its back.
+    ///
+    /// # Parameters
+    ///
+    /// * `inner` - The list the view operates on
+    pub fn new(inner: &'a mut List<T>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T> SingleEndedCollection for QueueView<'_, T> {
+    type Item = T;
+
+    fn pop(&mut self) -> Option<Self::Item> {
+        self.inner.pop_back()
+    }
+
+    fn peek(&self) -> Option<&Self::Item> {
+        self.inner.last()
+    }
+
+    fn peek_mut(&mut self) -> Option<&mut Self::Item> {
+        self.inner.last_mut()
+    }
+
+    fn push(&mut self, value: Self::Item) {
+        self.inner.push_front(value);
+    }
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<T> List<T> {
+    /// Borrows the list as a stack.
+    ///
+    /// The returned view pushes and pops at the back of the list.
+    ///
+    /// # Returns
+    ///
+    /// A `StackView` operating directly on this list
+    pub fn as_stack_mut(&mut self) -> StackView<'_, T> {
+        StackView::new(self)
+    }
+
+    /// Borrows the list as a queue.
+    ///
+    /// Like `Queue`, the returned view pushes at the front of the list and
+    /// pops at its back.
+    ///
+    /// # Returns
+    ///
+    /// A `QueueView` operating directly on this list
+    pub fn as_queue_mut(&mut self) -> QueueView<'_, T> {
+        QueueView::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stack_view() {
+        let mut list = List::from([1, 2]);
+        let mut stack = list.as_stack_mut();
+
+        // Elements come out in reverse order (LIFO), existing ones included
+        stack.push(3);
+        stack.push(4);
+        assert_eq!(stack.len(), 4);
+        assert_eq!(stack.peek(), Some(&4));
+        assert_eq!(stack.pop(), Some(4));
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), Some(2));
+
+        // The changes were applied to the borrowed list
+        assert_eq!(list, List::from([1]));
+    }
+
+    #[test]
+    fn test_queue_view() {
+        let mut list = List::new();
+        let mut queue = list.as_queue_mut();
+
+        // Elements come out in insertion order (FIFO)
+        queue.push(1);
+        queue.push(2);
+        queue.push(3);
+        assert_eq!(queue.peek(), Some(&1));
+        assert_eq!(queue.pop(), Some(1));
+        assert_eq!(queue.pop(), Some(2));
+        queue.push(4);
+        assert_eq!(queue.pop(), Some(3));
+        assert!(!queue.is_empty());
+
+        // The changes were applied to the borrowed list
+        assert_eq!(list, List::from([4]));
+    }
+}
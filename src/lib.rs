@@ -2,6 +2,92 @@ mod dequeue;
 mod list;
 
 pub mod prelude {
-    pub use super::dequeue::{single_ended_collection::SingleEndedCollection , queue::Queue, stack::Stack};
+    pub use super::dequeue::{
+        deque::Deque,
+        queue::Queue,
+        single_ended_collection::SingleEndedCollection,
+        stack::Stack,
+        view::{QueueView, StackView},
+    };
+    pub use super::list::errors::Errors;
     pub use super::list::linked_list::List;
+    pub use super::list::list_item::DetachedNode;
+    pub use crate::list;
+}
+
+/// Creates a `List` containing the arguments.
+///
+/// Like `vec!`, it comes in two forms:
+///
+/// * `list![a, b, c]` creates a list holding the given elements, in order
+/// * `list![value; count]` creates a list holding `count` clones of `value`
+///
+/// # Examples
+///
+/// ```
+/// use raw_double_linked_list::prelude::*;
+///
+/// let list = list![1, 2, 3];
+/// assert_eq!(list.len(), 3);
+///
+/// let zeros = list![0; 4];
+/// assert_eq!(zeros.iter().filter(|v| **v == 0).count(), 4);
+/// ```
+#[macro_export]
+macro_rules! list {
+    () => {
+        $crate::prelude::List::new()
+    };
+    ($value:expr; $count:expr) => {{
+        let mut list = $crate::prelude::List::new();
+        let value = $value;
+        let count: usize = $count;
+
+        // Clone for every element but the last, which takes the value itself
+        if count > 0 {
+            for _ in 1..count {
+                list.push_back(::core::clone::Clone::clone(&value));
+            }
+            list.push_back(value);
+        }
+        list
+    }};
+    ($($value:expr),+ $(,)?) => {
+        $crate::prelude::List::from([$($value),+])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::prelude::*;
+
+    #[test]
+    fn list_macro_elements_test() {
+        // Arrange
+        let empty: List<i32> = list![];
+        let mut expected = List::new();
+        expected.push_back(1);
+        expected.push_back(2);
+        expected.push_back(3);
+
+        // Assert
+        assert_eq!(list![1, 2, 3], expected);
+        assert_eq!(list![1, 2, 3,], expected);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn list_macro_repeat_test() {
+        // Arrange
+        let list = list![0; 4];
+
+        // Assert
+        assert_eq!(list.len(), 4);
+        assert!(list.iter().all(|v| *v == 0));
+        assert!(list![String::from("a"); 0].is_empty());
+        assert_eq!(
+            list![String::from("a"); 2],
+            list!["a".to_string(), "a".to_string()]
+        );
+    }
 }
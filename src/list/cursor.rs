@@ -0,0 +1,391 @@
+//! Cursors over a `List` for positional navigation and editing.
+//!
+//! A cursor points at a single node of the list and moves one step at a time
+//! in either direction, so walking and editing around a position is O(1) per
+//! operation instead of walking from an end for every access. Besides the list
+//! elements, a cursor can point at a "ghost" position sitting between the last
+//! and the first element: moving past either end lands on it, and moving again
+//! wraps around to the other end.
+use super::linked_list::List;
+use super::list_item::ItemPtr;
+
+/// A read-only cursor over a `List`.
+pub struct Cursor<'a, T> {
+    list: &'a List<T>,
+    current: Option<ItemPtr<T>>,
+    index: usize,
+}
+
+// SAFETY: the cursor only points into the list it borrows and hands out
+// `&T`, it behaves like `&'a List<T>`.
+unsafe impl<T: Sync> Send for Cursor<'_, T> {}
+unsafe impl<T: Sync> Sync for Cursor<'_, T> {}
+
+impl<'a, T> Cursor<'a, T> {
+    pub(crate) fn new(list: &'a List<T>, current: Option<ItemPtr<T>>, index: usize) -> Self {
+        Self {
+            list,
+            current,
+            index,
+        }
+    }
+
+    /// Returns the index of the element the cursor points at.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(usize)` if the cursor points at an element
+    /// * `None` if the cursor points at the ghost position
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    /// Moves the cursor to the next element.
+    ///
+    /// From the last element the cursor moves to the ghost position, and
+    /// from the ghost position it moves to the first element.
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(ptr) => {
+                self.current = unsafe { (*ptr).next };
+                self.index += 1;
+            }
+            None => {
+                self.current = self.list.start;
+                self.index = 0;
+            }
+        }
+    }
+
+    /// Moves the cursor to the previous element.
+    ///
+    /// From the first element the cursor moves to the ghost position, and
+    /// from the ghost position it moves to the last element.
+    pub fn move_prev(&mut self) {
+        match self.current {
+            Some(ptr) => {
+                self.current = unsafe { (*ptr).previous };
+                self.index = match self.current {
+                    Some(_) => self.index - 1,
+                    None => self.list.len,
+                };
+            }
+            None => {
+                self.current = self.list.end;
+                self.index = self.list.len.saturating_sub(1);
+            }
+        }
+    }
+
+    /// Returns a reference to the element the cursor points at.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` if the cursor points at an element
+    /// * `None` if the cursor points at the ghost position
+    pub fn current(&self) -> Option<&'a T> {
+        self.current.map(|ptr| unsafe { &(*ptr).value })
+    }
+}
+
+/// A cursor over a `List` that can edit the list around its position.
+pub struct CursorMut<'a, T> {
+    list: &'a mut List<T>,
+    current: Option<ItemPtr<T>>,
+    index: usize,
+}
+
+// SAFETY: the cursor only points into the list it mutably borrows,
+// it behaves like `&'a mut List<T>`.
+unsafe impl<T: Send> Send for CursorMut<'_, T> {}
+unsafe impl<T: Sync> Sync for CursorMut<'_, T> {}
+
+impl<'a, T> CursorMut<'a, T> {
+    pub(crate) fn new(list: &'a mut List<T>, current: Option<ItemPtr<T>>, index: usize) -> Self {
+        Self {
+            list,
+            current,
+            index,
+        }
+    }
+
+    /// Returns the index of the element the cursor points at.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(usize)` if the cursor points at an element
+    /// * `None` if the cursor points at the ghost position
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    /// Moves the cursor to the next element.
+    ///
+    /// From the last element the cursor moves to the ghost position, and
+    /// from the ghost position it moves to the first element.
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(ptr) => {
+                self.current = unsafe { (*ptr).next };
+                self.index += 1;
+            }
+            None => {
+                self.current = self.list.start;
+                self.index = 0;
+            }
+        }
+    }
+
+    /// Moves the cursor to the previous element.
+    ///
+    /// From the first element the cursor moves to the ghost position, and
+    /// from the ghost position it moves to the last element.
+    pub fn move_prev(&mut self) {
+        match self.current {
+            Some(ptr) => {
+                self.current = unsafe { (*ptr).previous };
+                self.index = match self.current {
+                    Some(_) => self.index - 1,
+                    None => self.list.len,
+                };
+            }
+            None => {
+                self.current = self.list.end;
+                self.index = self.list.len.saturating_sub(1);
+            }
+        }
+    }
+
+    /// Returns a mutable reference to the element the cursor points at.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&mut T)` if the cursor points at an element
+    /// * `None` if the cursor points at the ghost position
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.current.map(|ptr| unsafe { &mut (*ptr).value })
+    }
+
+    /// Inserts an element right after the cursor position.
+    ///
+    /// The cursor doesn't move. When it points at the ghost position,
+    /// the element is inserted at the front of the list.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The value to insert
+    pub fn insert_after(&mut self, value: T) {
+        self.list._link_after(self.current, value);
+
+        // The ghost position always sits at index len
+        if self.current.is_none() {
+            self.index = self.list.len;
+        }
+    }
+
+    /// Inserts an element right before the cursor position.
+    ///
+    /// The cursor doesn't move. When it points at the ghost position,
+    /// the element is inserted at the back of the list.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The value to insert
+    pub fn insert_before(&mut self, value: T) {
+        self.list._link_before(self.current, value);
+
+        // Every element before the cursor shifts its index by one
+        self.index += 1;
+    }
+
+    /// Inserts all the items of an iterator right after the cursor position.
+    ///
+    /// The items keep their order and each one is linked after the previous
+    /// one, so this is O(k) for k items. The cursor doesn't move, it stays
+    /// right before the spliced items. When it points at the ghost position,
+    /// the items are inserted at the front of the list.
+    ///
+    /// # Parameters
+    ///
+    /// * `iter` - The items to insert
+    pub fn splice_after<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut anchor = self.current;
+        for value in iter {
+            anchor = Some(self.list._link_after(anchor, value));
+        }
+
+        // The ghost position always sits at index len
+        if self.current.is_none() {
+            self.index = self.list.len;
+        }
+    }
+
+    /// Removes the element the cursor points at.
+    ///
+    /// The cursor moves to the element that followed the removed one, or to
+    /// the ghost position if the removed element was the last one.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` containing the removed value
+    /// * `None` if the cursor points at the ghost position
+    pub fn remove_current(&mut self) -> Option<T> {
+        let ptr = self.current?;
+
+        // Move forward before freeing the node, the index stays the same
+        self.current = unsafe { (*ptr).next };
+        Some(self.list._unlink_node(ptr))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::linked_list::*;
+
+    #[test]
+    fn cursor_walk_test() {
+        // Arrange
+        let list = (0..3).collect::<List<_>>();
+        let mut cursor = list.cursor_front();
+
+        // Assert
+        assert_eq!(cursor.current(), Some(&0));
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&2));
+        assert_eq!(cursor.index(), Some(2));
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.index(), None);
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&0));
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&2));
+        assert_eq!(cursor.index(), Some(2));
+    }
+
+    #[test]
+    fn cursor_back_test() {
+        // Arrange
+        let list = (0..3).collect::<List<_>>();
+        let mut cursor = list.cursor_back();
+
+        // Assert
+        assert_eq!(cursor.current(), Some(&2));
+        assert_eq!(cursor.index(), Some(2));
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&1));
+        assert_eq!(cursor.index(), Some(1));
+    }
+
+    #[test]
+    fn cursor_mut_insert_remove_test() {
+        // Arrange
+        let mut list = (0..5).collect::<List<_>>();
+        let mut cursor = list.cursor_front_mut();
+
+        // Act
+        cursor.move_next();
+        cursor.move_next();
+        cursor.insert_after(10);
+        let removed = cursor.remove_current();
+
+        // Assert
+        assert_eq!(removed, Some(2));
+        assert_eq!(cursor.current(), Some(&mut 10));
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(list.len(), 5);
+        assert_eq!(list, List::from_iter([0, 1, 10, 3, 4]));
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![4, 3, 10, 1, 0]
+        );
+    }
+
+    #[test]
+    fn cursor_mut_insert_before_test() {
+        // Arrange
+        let mut list = (0..3).collect::<List<_>>();
+        let mut cursor = list.cursor_front_mut();
+
+        // Act
+        cursor.insert_before(-1);
+        cursor.move_next();
+        cursor.insert_before(5);
+
+        // Assert
+        assert_eq!(cursor.current(), Some(&mut 1));
+        assert_eq!(cursor.index(), Some(3));
+        assert_eq!(list, List::from_iter([-1, 0, 5, 1, 2]));
+        assert_eq!(list.first(), Some(&-1));
+    }
+
+    #[test]
+    fn cursor_mut_ends_test() {
+        // Arrange
+        let mut list = List::new();
+        let mut cursor = list.cursor_front_mut();
+
+        // Act (on the ghost, after inserts at the front and before at the back)
+        cursor.insert_after(1);
+        cursor.insert_before(2);
+        cursor.insert_after(0);
+
+        // Assert
+        assert_eq!(cursor.index(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&mut 2));
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(cursor.remove_current(), Some(2));
+        assert_eq!(cursor.current(), None);
+        assert_eq!(list, List::from_iter([0, 1]));
+        assert_eq!(list.last(), Some(&1));
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn cursor_mut_splice_after_test() {
+        // Arrange
+        let mut list = (0..3).collect::<List<_>>();
+        let mut cursor = list.cursor_front_mut();
+
+        // Act
+        cursor.move_next();
+        cursor.splice_after(10..13);
+
+        // Assert
+        assert_eq!(cursor.current(), Some(&mut 1));
+        assert_eq!(cursor.index(), Some(1));
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 10));
+        assert_eq!(list.len(), 6);
+        assert_eq!(list, List::from_iter([0, 1, 10, 11, 12, 2]));
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![2, 12, 11, 10, 1, 0]
+        );
+    }
+
+    #[test]
+    fn cursor_mut_splice_after_ends_test() {
+        // Arrange
+        let mut list = List::from([5]);
+        let mut cursor = list.cursor_back_mut();
+
+        // Act (after the last element, then on the ghost)
+        cursor.splice_after([6, 7]);
+        cursor.move_next();
+        cursor.move_next();
+        cursor.move_next();
+        cursor.splice_after([3, 4]);
+        cursor.splice_after(std::iter::empty());
+
+        // Assert
+        assert_eq!(cursor.index(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.index(), Some(4));
+        assert_eq!(list, List::from_iter([3, 4, 5, 6, 7]));
+        assert_eq!((list.first(), list.last()), (Some(&3), Some(&7)));
+    }
+}
@@ -3,6 +3,54 @@ pub enum Errors {
     /// Indicates an access attempt outside the valid range of indices
     OutOfBounds,
 
+    /// Indicates the list length can't grow without overflowing `usize`
+    LengthOverflow,
+
+    /// Indicates the given indices aren't a permutation of the list indices
+    InvalidPermutation,
+
     /// Indicates an internal inconsistency in the list structure
     InternalError,
 }
+
+impl std::fmt::Display for Errors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            Errors::OutOfBounds => "index out of bounds",
+            Errors::LengthOverflow => "list length overflow",
+            Errors::InvalidPermutation => "invalid permutation of the list indices",
+            Errors::InternalError => "internal list inconsistency",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for Errors {}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn display_out_of_bounds_test() {
+        // Arrange
+        let mut list = List::from([1, 2, 3]);
+
+        // Act
+        let error = list.insert(5, 4).unwrap_err();
+
+        // Assert
+        assert!(matches!(error, Errors::OutOfBounds));
+        assert_eq!(error.to_string(), "index out of bounds");
+        assert_eq!(format!("{error:?}"), "OutOfBounds");
+    }
+
+    #[test]
+    fn error_trait_test() {
+        // Arrange
+        let error: Box<dyn std::error::Error> = Box::new(Errors::LengthOverflow);
+
+        // Assert
+        assert_eq!(error.to_string(), "list length overflow");
+    }
+}
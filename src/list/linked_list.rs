@@ -3,15 +3,24 @@
 //! This module provides a general-purpose doubly-linked list with O(1) operations
 //! for adding or removing elements at either end. The list maintains pointers to
 //! both ends to enable efficient bidirectional access.
-use std::fmt::Debug;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::{Debug, Display, Write};
+use std::hash::Hash;
 use std::marker::PhantomData;
-use std::ops::{Index, IndexMut};
+use std::mem::MaybeUninit;
+use std::ops::{Add, AddAssign, ControlFlow, Index, IndexMut, RangeBounds};
 
+use super::cursor::{Cursor, CursorMut};
 use super::errors::Errors;
-use super::list_item::{DoubleLinkedListItem, ItemPtr};
-use super::list_iter::ListIter;
-use super::list_sort::{merge_sort_by, UpdateListBound};
-use super::list_utility::{find_index_through, get_ptr_starting_point, Side};
+use super::list_into_iter::IntoIter;
+use super::list_item::{DetachedNode, DoubleLinkedListItem, ItemPtr};
+use super::list_iter::{
+    Chunks, Drain, GroupBy, IndexedIter, ListIter, ListIterMut, RChunks, Windows,
+};
+use super::list_sort::{merge, merge_sort_by, UpdateListBound};
+use super::list_utility::{
+    checked_len_add, find_index_through, get_ptr_starting_point, resolve_range, Side,
+};
 
 /// A doubly-linked list with pointers to both ends.
 ///
@@ -20,9 +29,11 @@ use super::list_utility::{find_index_through, get_ptr_starting_point, Side};
 /// to prevent memory leaks.
 #[derive(Default)]
 pub struct List<T> {
-    start: Option<ItemPtr<T>>,
-    end: Option<ItemPtr<T>>,
-    len: usize,
+    pub(crate) start: Option<ItemPtr<T>>,
+    pub(crate) end: Option<ItemPtr<T>>,
+    pub(crate) len: usize,
+    pub(crate) recycled: Vec<Box<MaybeUninit<DoubleLinkedListItem<T>>>>,
+    pub(crate) recycle_limit: usize,
 }
 
 impl<T> List<T> {
@@ -36,6 +47,8 @@ impl<T> List<T> {
             start: None,
             end: None,
             len: 0,
+            recycled: Vec::new(),
+            recycle_limit: 0,
         }
     }
 
@@ -44,15 +57,20 @@ impl<T> List<T> {
     /// # Parameters
     ///
     /// * `value` - The value to add to the list
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list length would overflow `usize`.
     pub fn push_back(&mut self, value: T) {
+        // Increment the len first so an overflow can't leak the new node
+        self.len = checked_len_add(self.len, 1);
+
         // Instanciate a heap alloc item and obtain a raw mutable ptr to it
-        let new_item = Box::new(DoubleLinkedListItem {
+        let raw_ptr = self._alloc_node(DoubleLinkedListItem {
             value,
             next: None,
             previous: None,
         });
-        let raw_ptr = Box::into_raw(new_item);
-        self.len += 1;
 
         // start and end or both Some or both None
         // If they're none, we have to init both
@@ -78,15 +96,20 @@ impl<T> List<T> {
     /// # Parameters
     ///
     /// * `value` - The value to add to the list
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list length would overflow `usize`.
     pub fn push_front(&mut self, value: T) {
+        // Increment the len first so an overflow can't leak the new node
+        self.len = checked_len_add(self.len, 1);
+
         // Instanciate a heap alloc item and obtain a raw mutable ptr to it
-        let new_item = Box::new(DoubleLinkedListItem {
+        let raw_ptr = self._alloc_node(DoubleLinkedListItem {
             value,
             next: None,
             previous: None,
         });
-        let raw_ptr = Box::into_raw(new_item);
-        self.len += 1;
 
         // start and end or both Some or both None
         // If they're none, we have to init both
@@ -118,6 +141,7 @@ impl<T> List<T> {
     ///
     /// * `Ok(())` if the insertion was successful
     /// * `Err(Errors::OutOfBounds)` if the index is greater than the list length
+    /// * `Err(Errors::LengthOverflow)` if the list length can't grow anymore
     /// * `Err(Errors::InternalError)` if an internal error occurred
     ///
     /// # Note
@@ -125,6 +149,11 @@ impl<T> List<T> {
     /// If `index == 0`, this is equivalent to `push_front()`.
     /// If `index == self.len()`, this is equivalent to `push_back()`.
     pub fn insert(&mut self, index: usize, value: T) -> Result<(), Errors> {
+        // Refuse to grow a list that can't count one more element
+        if self.len.checked_add(1).is_none() {
+            return Err(Errors::LengthOverflow);
+        }
+
         // Check if it couldn't be replace with push back or front
         if index == 0 {
             self.push_front(value);
@@ -137,12 +166,11 @@ impl<T> List<T> {
         }
 
         // Instanciate a heap alloc item and obtain a raw mutable ptr to it
-        let new_item = Box::new(DoubleLinkedListItem {
+        let raw_ptr = self._alloc_node(DoubleLinkedListItem {
             value,
             next: None,
             previous: None,
         });
-        let raw_ptr = Box::into_raw(new_item);
 
         // get the before ptr
         let before_ptr_opt = self._get_ptr_at_index(index - 1);
@@ -162,7 +190,7 @@ impl<T> List<T> {
             }
 
             // Increment the len
-            self.len += 1;
+            self.len = checked_len_add(self.len, 1);
             Ok(())
         } else {
             Err(Errors::InternalError)
@@ -196,11 +224,8 @@ impl<T> List<T> {
         // Decrement length
         self.len -= 1;
 
-        // Convert the raw pointer back to a Box and return the value
-        unsafe {
-            let box_item = Box::from_raw(front_ptr);
-            Some(box_item.value)
-        }
+        // Release the node and return the value
+        Some(self._free_node(front_ptr))
     }
 
     /// Removes and returns the last element of the list.
@@ -230,11 +255,8 @@ impl<T> List<T> {
         // Decrement length
         self.len -= 1;
 
-        // Convert the raw pointer back to a Box and return the value
-        unsafe {
-            let box_item = Box::from_raw(back_ptr);
-            Some(box_item.value)
-        }
+        // Release the node and return the value
+        Some(self._free_node(back_ptr))
     }
 
     /// Returns the current number of elements in the list.
@@ -283,10 +305,25 @@ impl<T> List<T> {
     /// # Returns
     ///
     /// A bidirectional iterator for traversing the list
-    pub fn iter(&self) -> ListIter<T> {
+    pub fn iter(&self) -> ListIter<'_, T> {
         ListIter {
             left: self.start,
             right: self.end,
+            remaining: self.len,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Creates an iterator over mutable references to the list elements.
+    ///
+    /// # Returns
+    ///
+    /// A bidirectional iterator for traversing and mutating the list
+    pub fn iter_mut(&mut self) -> ListIterMut<'_, T> {
+        ListIterMut {
+            left: self.start,
+            right: self.end,
+            remaining: self.len,
             _phantom: PhantomData,
         }
     }
@@ -303,7 +340,7 @@ impl<T> List<T> {
     ///
     /// * `Some(ItemPtr<T>)` if the index is within bounds
     /// * `None` if the index is out of bounds or the list is empty
-    fn _get_ptr_at_index(&self, index: usize) -> Option<ItemPtr<T>> {
+    pub(crate) fn _get_ptr_at_index(&self, index: usize) -> Option<ItemPtr<T>> {
         // Early exit condition
         if self.len == 0 || index >= self.len {
             return None;
@@ -322,6 +359,185 @@ impl<T> List<T> {
         find_index_through(raw_ptr, index, self.len, &side)
     }
 
+    /// Detaches a node from the list and returns its value.
+    ///
+    /// This is an internal helper method used by other list methods. The
+    /// neighbours of the node are stitched together and the list bounds
+    /// are updated when the node was the head or the tail.
+    ///
+    /// # Parameters
+    ///
+    /// * `ptr` - A pointer to a node that belongs to this list
+    ///
+    /// # Returns
+    ///
+    /// The value that was held by the node
+    pub(crate) fn _unlink_node(&mut self, ptr: ItemPtr<T>) -> T {
+        self._detach_node(ptr);
+
+        // Release the node and return the value
+        self._free_node(ptr)
+    }
+
+    /// Detaches a node from the list without freeing it.
+    ///
+    /// This is an internal helper method used by other list methods. The
+    /// neighbours of the node are stitched together, the list bounds and
+    /// length are updated, and the node is left with no links.
+    ///
+    /// # Parameters
+    ///
+    /// * `ptr` - A pointer to a node that belongs to this list
+    pub(crate) fn _detach_node(&mut self, ptr: ItemPtr<T>) {
+        // Take both neighbours, the node ends up unlinked
+        let (previous, next) = unsafe { ((*ptr).previous.take(), (*ptr).next.take()) };
+
+        // Connect the previous node (or the start) to the next one
+        match previous {
+            Some(previous_ptr) => unsafe { (*previous_ptr).next = next },
+            None => self.start = next,
+        }
+
+        // Connect the next node (or the end) to the previous one
+        match next {
+            Some(next_ptr) => unsafe { (*next_ptr).previous = previous },
+            None => self.end = previous,
+        }
+
+        // Decrement length
+        self.len -= 1;
+    }
+
+    /// Allocates a node for `item`, reusing a recycled allocation if any.
+    ///
+    /// This is an internal helper method used by other list methods.
+    ///
+    /// # Parameters
+    ///
+    /// * `item` - The node to move on the heap
+    ///
+    /// # Returns
+    ///
+    /// A pointer to the new node, not linked to the list yet
+    pub(crate) fn _alloc_node(&mut self, item: DoubleLinkedListItem<T>) -> ItemPtr<T> {
+        match self.recycled.pop() {
+            Some(mut slot) => {
+                slot.write(item);
+                // `MaybeUninit<X>` has the same layout as `X`
+                Box::into_raw(slot).cast::<DoubleLinkedListItem<T>>()
+            }
+            None => Box::into_raw(Box::new(item)),
+        }
+    }
+
+    /// Frees a node that was unlinked from the list and returns its value.
+    ///
+    /// This is an internal helper method used by other list methods. The
+    /// allocation is kept for a later push while the recycle pool has room.
+    ///
+    /// # Parameters
+    ///
+    /// * `ptr` - The unlinked node to free
+    ///
+    /// # Returns
+    ///
+    /// The value the node was holding
+    pub(crate) fn _free_node(&mut self, ptr: ItemPtr<T>) -> T {
+        // Take the allocation back without dropping anything yet
+        let slot = unsafe { Box::from_raw(ptr.cast::<MaybeUninit<DoubleLinkedListItem<T>>>()) };
+        let item = unsafe { slot.assume_init_read() };
+
+        // An emptied slot is kept or deallocated, it never drops a value twice
+        if self.recycled.len() < self.recycle_limit {
+            self.recycled.push(slot);
+        }
+        item.value
+    }
+
+    /// Links a new node holding `value` right after `anchor`.
+    ///
+    /// This is an internal helper method used by other list methods.
+    ///
+    /// # Parameters
+    ///
+    /// * `anchor` - The node to insert after, `None` inserts at the front
+    /// * `value` - The value to insert
+    ///
+    /// # Returns
+    ///
+    /// A pointer to the newly linked node
+    pub(crate) fn _link_after(&mut self, anchor: Option<ItemPtr<T>>, value: T) -> ItemPtr<T> {
+        // Inserting after nothing means inserting at the front
+        let Some(anchor_ptr) = anchor else {
+            self.push_front(value);
+            return self.start.unwrap();
+        };
+
+        // Increment the len first so an overflow can't leak the new node
+        self.len = checked_len_add(self.len, 1);
+
+        // Instanciate a heap alloc item and obtain a raw mutable ptr to it
+        let raw_ptr = self._alloc_node(DoubleLinkedListItem {
+            value,
+            next: None,
+            previous: Some(anchor_ptr),
+        });
+
+        // Stitch the new node between the anchor and its successor
+        unsafe {
+            let next = (*anchor_ptr).next.replace(raw_ptr);
+            (*raw_ptr).next = next;
+            match next {
+                Some(next_ptr) => (*next_ptr).previous = Some(raw_ptr),
+                None => self.end = Some(raw_ptr),
+            }
+        }
+
+        raw_ptr
+    }
+
+    /// Links a new node holding `value` right before `anchor`.
+    ///
+    /// This is an internal helper method used by other list methods.
+    ///
+    /// # Parameters
+    ///
+    /// * `anchor` - The node to insert before, `None` inserts at the back
+    /// * `value` - The value to insert
+    ///
+    /// # Returns
+    ///
+    /// A pointer to the newly linked node
+    pub(crate) fn _link_before(&mut self, anchor: Option<ItemPtr<T>>, value: T) -> ItemPtr<T> {
+        // Inserting before nothing means inserting at the back
+        let Some(anchor_ptr) = anchor else {
+            self.push_back(value);
+            return self.end.unwrap();
+        };
+
+        // Increment the len first so an overflow can't leak the new node
+        self.len = checked_len_add(self.len, 1);
+
+        // Instanciate a heap alloc item and obtain a raw mutable ptr to it
+        let raw_ptr = self._alloc_node(DoubleLinkedListItem {
+            value,
+            next: Some(anchor_ptr),
+            previous: None,
+        });
+
+        // Stitch the new node between the anchor and its predecessor
+        unsafe {
+            let previous = (*anchor_ptr).previous.replace(raw_ptr);
+            (*raw_ptr).previous = previous;
+            match previous {
+                Some(previous_ptr) => (*previous_ptr).next = Some(raw_ptr),
+                None => self.start = Some(raw_ptr),
+            }
+        }
+
+        raw_ptr
+    }
+
     /// Returns a reference to the element at the specified index.
     ///
     /// # Parameters
@@ -352,6 +568,52 @@ impl<T> List<T> {
         raw_ptr.map(|ptr| unsafe { &mut (*ptr).value })
     }
 
+    /// Returns mutable references to the elements at two distinct indices.
+    ///
+    /// # Parameters
+    ///
+    /// * `i` - The index of the first element
+    /// * `j` - The index of the second element
+    ///
+    /// # Returns
+    ///
+    /// * `Some((&mut T, &mut T))` if both indices are within bounds and distinct
+    /// * `None` if `i == j` or either index is out of bounds
+    pub fn get_pair_mut(&mut self, i: usize, j: usize) -> Option<(&mut T, &mut T)> {
+        if i == j {
+            return None;
+        }
+        let first_ptr = self._get_ptr_at_index(i)?;
+        let second_ptr = self._get_ptr_at_index(j)?;
+
+        // Distinct indices are distinct nodes, so the references don't alias
+        unsafe { Some((&mut (*first_ptr).value, &mut (*second_ptr).value)) }
+    }
+
+    /// Swaps the values at two indices.
+    ///
+    /// Only the values are swapped, the nodes stay linked in place.
+    ///
+    /// # Parameters
+    ///
+    /// * `i` - The index of the first element
+    /// * `j` - The index of the second element
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the values were swapped, or if `i == j`
+    /// * `Err(Errors::OutOfBounds)` if either index is out of bounds
+    pub fn swap(&mut self, i: usize, j: usize) -> Result<(), Errors> {
+        let first_ptr = self._get_ptr_at_index(i).ok_or(Errors::OutOfBounds)?;
+        let second_ptr = self._get_ptr_at_index(j).ok_or(Errors::OutOfBounds)?;
+
+        // Swapping a node with itself is a no-op
+        if first_ptr != second_ptr {
+            unsafe { std::mem::swap(&mut (*first_ptr).value, &mut (*second_ptr).value) };
+        }
+        Ok(())
+    }
+
     /// Checks if the list is empty.
     ///
     /// # Returns
@@ -363,9 +625,20 @@ impl<T> List<T> {
 
     /// Removes all elements from the list.
     ///
-    /// This method calls `pop_front()` repeatedly until the list is empty.
+    /// The nodes are freed in a single walk from the front, like `Drop` does,
+    /// and the bounds are reset once. Freed nodes go to the recycle pool while
+    /// it has room.
     pub fn clear(&mut self) {
-        while self.pop_front().is_some() {}
+        // Detach the whole chain first, a panicking drop then only leaks nodes
+        let mut ptr_opt = self.start.take();
+        self.end = None;
+        self.len = 0;
+
+        while let Some(ptr) = ptr_opt {
+            // get next before the node is freed
+            ptr_opt = unsafe { (*ptr).next };
+            drop(self._free_node(ptr));
+        }
     }
 
     /// Returns a reference to the first element of the list.
@@ -388,6 +661,26 @@ impl<T> List<T> {
         self.end.map(|ptr| unsafe { &(*ptr).value })
     }
 
+    /// Returns a mutable reference to the first element of the list.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&mut T)` if the list is not empty
+    /// * `None` if the list is empty
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.start.map(|ptr| unsafe { &mut (*ptr).value })
+    }
+
+    /// Returns a mutable reference to the last element of the list.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&mut T)` if the list is not empty
+    /// * `None` if the list is empty
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        self.end.map(|ptr| unsafe { &mut (*ptr).value })
+    }
+
     /// Concatenates another list to the end of this list.
     ///
     /// # Arguments
@@ -402,25 +695,115 @@ impl<T> List<T> {
     ///
     /// After concatenation, other is left empty but in a valid state.
     pub fn concatenate(&mut self, mut other: List<T>) {
+        self.append(&mut other);
+    }
+
+    /// Moves all the elements of another list to the end of this list.
+    ///
+    /// This is O(1): the nodes of `other` are relinked, not reallocated.
+    ///
+    /// # Arguments
+    ///
+    /// * other - List to append to this list. It is left empty and reusable.
+    pub fn append(&mut self, other: &mut List<T>) {
         // First, ensure any of the list are empty (or not)
         if self.is_empty() {
-            *self = other;
+            // Only the nodes move, each list keeps its own recycle pool
+            std::mem::swap(&mut self.start, &mut other.start);
+            std::mem::swap(&mut self.end, &mut other.end);
+            std::mem::swap(&mut self.len, &mut other.len);
             return;
         } else if other.is_empty() {
             return;
         }
 
+        // Both lists hold elements, a missing bound would drop nodes silently
+        debug_assert!(
+            self.start.is_some() && self.end.is_some(),
+            "non-empty list without bounds"
+        );
+        debug_assert!(
+            other.start.is_some() && other.end.is_some(),
+            "non-empty appended list without bounds"
+        );
+
+        // Check the length before relinking, a panic must leave both lists intact
+        let new_len = checked_len_add(self.len, other.len);
+
         // chain the other list to the current one
-        self.end.map(|node| unsafe { (*node).next = other.start });
-        other
-            .start
-            .map(|node| unsafe { (*node).previous = self.end });
+        if let Some(node) = self.end {
+            unsafe { (*node).next = other.start };
+        }
+        if let Some(node) = other.start {
+            unsafe { (*node).previous = self.end };
+        }
         self.end = other.end;
-        self.len += other.len;
+        self.len = new_len;
+
+        // Leave other as a valid empty list
+        other.start = None;
+        other.end = None;
+        other.len = 0;
+    }
+
+    /// Inserts all the elements of another list at the specified index.
+    ///
+    /// The nodes of `other` are relinked into this list, nothing is moved
+    /// or reallocated. Finding the insertion point walks from the nearer end.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index at which the first element of `other` will be
+    /// * `other` - List to splice into this list. Takes ownership of other.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the splice was successful
+    /// * `Err(Errors::OutOfBounds)` if the index is greater than the list length
+    /// * `Err(Errors::InternalError)` if an internal error occurred
+    ///
+    /// # Note
+    ///
+    /// If `index == self.len()`, this is equivalent to `concatenate()`.
+    pub fn splice_at(&mut self, index: usize, mut other: List<T>) -> Result<(), Errors> {
+        if index > self.len {
+            return Err(Errors::OutOfBounds);
+        } else if index == self.len {
+            self.concatenate(other);
+            return Ok(());
+        } else if other.is_empty() {
+            return Ok(());
+        }
+
+        // The node that will follow the spliced chain
+        let after_ptr = self._get_ptr_at_index(index).ok_or(Errors::InternalError)?;
+        let (other_start, other_end) = match (other.start, other.end) {
+            (Some(start), Some(end)) => (start, end),
+            _ => return Err(Errors::InternalError),
+        };
+
+        // Check the length before relinking, a panic must leave both lists intact
+        let new_len = checked_len_add(self.len, other.len);
+
+        unsafe {
+            // Connect the node before the insertion point (or the start)
+            let before = (*after_ptr).previous.replace(other_end);
+            match before {
+                Some(before_ptr) => (*before_ptr).next = Some(other_start),
+                None => self.start = Some(other_start),
+            }
+
+            // Connect both ends of the chain
+            (*other_start).previous = before;
+            (*other_end).next = Some(after_ptr);
+        }
+        self.len = new_len;
 
         // Set other.end and other.start to none
         other.start = None;
         other.end = None;
+        other.len = 0;
+        Ok(())
     }
 
     /// Sorts the list in-place according to the given comparison function.
@@ -451,546 +834,5203 @@ impl<T> List<T> {
         let UpdateListBound { new_start, new_end } =
             merge_sort_by(self.start.unwrap(), self.len, f);
 
+        // Walk the sorted chain to catch a merge losing or duplicating nodes
+        debug_assert_eq!(
+            std::iter::successors(Some(new_start), |&ptr| unsafe { (*ptr).next }).count(),
+            self.len,
+            "sorted chain doesn't hold `len` nodes"
+        );
+
         // update bounds of the list
         self.start = Some(new_start);
         self.end = Some(new_end);
     }
-}
 
-impl<T: Ord> List<T> {
-    /// Sorts the list in ascending order.
+    /// Returns a cursor pointing at the first element of the list.
     ///
-    /// This method sorts the elements of the list in place, using their natural ordering.
-    /// Elements must implement the `Ord` trait to use this method.
+    /// On an empty list the cursor points at the "ghost" position.
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
+        Cursor::new(self, self.start, 0)
+    }
+
+    /// Returns a cursor pointing at the last element of the list.
     ///
-    /// The sort operation is stable: equal elements retain their relative order.
-    /// The algorithm has O(n log n) time complexity and uses a merge sort implementation.
-    pub fn sort(&mut self) {
-        self.sort_by(|a, b| a.cmp(b));
+    /// On an empty list the cursor points at the "ghost" position.
+    pub fn cursor_back(&self) -> Cursor<'_, T> {
+        let index = self.len.saturating_sub(1);
+        Cursor::new(self, self.end, index)
     }
-}
 
-impl<T> Drop for List<T> {
-    fn drop(&mut self) {
-        // Start on the begin ptr and iterate until we arrive to the end
-        let mut ptr_opt = self.start;
+    /// Returns a mutable cursor pointing at the first element of the list.
+    ///
+    /// On an empty list the cursor points at the "ghost" position.
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        let start = self.start;
+        CursorMut::new(self, start, 0)
+    }
 
-        // Iterate
-        while let Some(ptr) = ptr_opt {
-            // get next
-            let next = unsafe { (*ptr).next };
+    /// Returns a mutable cursor pointing at the last element of the list.
+    ///
+    /// On an empty list the cursor points at the "ghost" position.
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        let (end, index) = (self.end, self.len.saturating_sub(1));
+        CursorMut::new(self, end, index)
+    }
 
-            // Drop the node (taking ownership with a box will drop it)
-            // At the end of the iteration
-            unsafe {
-                let _ = Box::from_raw(ptr);
+    /// Folds the elements from front to back until the closure breaks.
+    ///
+    /// # Arguments
+    ///
+    /// * `init` - The initial value of the accumulator
+    /// * `f` - A closure returning `ControlFlow::Continue` with the new
+    ///   accumulator to keep folding, or `ControlFlow::Break` to stop
+    ///
+    /// # Returns
+    ///
+    /// The accumulator carried by the first `Break`, or the final
+    /// accumulator if the whole list was folded.
+    pub fn fold_while<B, F>(&self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, &T) -> ControlFlow<B, B>,
+    {
+        let mut accumulator = init;
+        for item in self.iter() {
+            match f(accumulator, item) {
+                ControlFlow::Continue(next) => accumulator = next,
+                ControlFlow::Break(result) => return result,
             }
-
-            // Move to next
-            ptr_opt = next;
         }
-
-        // Cls other ptrs
-        self.start = None;
-        self.end = None;
+        accumulator
     }
-}
-
-impl<A> FromIterator<A> for List<A> {
-    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
-        let mut new_list = List::new();
-
-        // Iterate through original list and add each value to the new list
-        for item in iter {
-            new_list.push_back(item);
-        }
 
-        new_list
+    /// Sorts the list in-place by the key extracted from each element.
+    ///
+    /// The sort is stable and relies on `sort_by`, comparing the keys
+    /// returned by `f` with their natural ordering.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A function extracting the key used to order the elements
+    pub fn sort_by_key<K, F>(&mut self, f: F)
+    where
+        K: Ord,
+        F: Fn(&T) -> K + Copy,
+    {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
     }
-}
-
-impl<T: Clone> Clone for List<T> {
-    fn clone(&self) -> Self {
-        let mut new_list = List::new();
 
-        // Iterate through original list and add each value to the new list
+    /// Retains only the elements within `range` that satisfy the predicate.
+    ///
+    /// Elements outside of the range are left untouched, whatever the
+    /// predicate would have returned for them.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The range of indices the filter applies to
+    /// * `f` - A predicate returning `true` for the elements to keep
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the range was valid and the list was filtered
+    /// * `Err(Errors::OutOfBounds)` if the range doesn't fit in the list
+    pub fn retain_range<R, F>(&mut self, range: R, mut f: F) -> Result<(), Errors>
+    where
+        R: RangeBounds<usize>,
+        F: FnMut(&T) -> bool,
+    {
+        let (start, end) = resolve_range(&range, self.len).ok_or(Errors::OutOfBounds)?;
+
+        // Nothing to filter
+        if start == end {
+            return Ok(());
+        }
+
+        // Walk from the first node of the range
+        let mut current = self._get_ptr_at_index(start);
+        for _ in start..end {
+            let ptr = current.ok_or(Errors::InternalError)?;
+
+            // Save the next node before we possibly free the current one
+            current = unsafe { (*ptr).next };
+
+            if !f(unsafe { &(*ptr).value }) {
+                self._unlink_node(ptr);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether the list contains an element equal to `value`.
+    ///
+    /// The search stops at the first match.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The value to look for
+    ///
+    /// # Returns
+    ///
+    /// `true` if an equal element is found, `false` otherwise
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|item| item == value)
+    }
+
+    /// Returns the index of the first element satisfying the predicate.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicate` - A predicate returning `true` for the searched element
+    ///
+    /// # Returns
+    ///
+    /// * `Some(usize)` with the zero-based index of the first match
+    /// * `None` if no element satisfies the predicate
+    pub fn position<P>(&self, predicate: P) -> Option<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter().position(predicate)
+    }
+
+    /// Describes every maximal run of equal consecutive elements.
+    ///
+    /// The list is left untouched, which makes it handy to inspect the data
+    /// before deciding whether to deduplicate it.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` of `(start_index, run_length)` tuples, in list order
+    pub fn run_stats(&self) -> Vec<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        let mut runs: Vec<(usize, usize)> = Vec::new();
+        let mut previous: Option<&T> = None;
+
+        for (index, item) in self.iter().enumerate() {
+            match (previous, runs.last_mut()) {
+                // Same value as the previous one, the run goes on
+                (Some(prev), Some((_, run_len))) if prev == item => *run_len += 1,
+                // Otherwise a new run starts here
+                _ => runs.push((index, 1)),
+            }
+            previous = Some(item);
+        }
+
+        runs
+    }
+
+    /// Retains only the elements that satisfy the predicate.
+    ///
+    /// The nodes are walked once from front to back, every node whose element
+    /// fails the predicate is unlinked and freed.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A predicate returning `true` for the elements to keep
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|item| f(item));
+    }
+
+    /// Retains only the elements that satisfy the predicate, which can mutate them.
+    ///
+    /// Works like `retain`, but the predicate receives `&mut T` so elements
+    /// can be updated and filtered in the same pass, like `Vec::retain_mut`.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A predicate returning `true` for the elements to keep
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let mut current = self.start;
+        while let Some(ptr) = current {
+            // Save the next node before we possibly free the current one
+            current = unsafe { (*ptr).next };
+
+            if !f(unsafe { &mut (*ptr).value }) {
+                self._unlink_node(ptr);
+            }
+        }
+    }
+
+    /// Splits the list into two at the given index.
+    ///
+    /// The split point is found by walking from the nearer end, so this is
+    /// O(min(at, len - at)). No element is moved or reallocated.
+    ///
+    /// # Arguments
+    ///
+    /// * `at` - The index of the first element of the returned list
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(List<T>)` containing the elements `[at, len)`, `self` keeps `[0, at)`
+    /// * `Err(Errors::OutOfBounds)` if `at` is greater than the list length
+    /// * `Err(Errors::InternalError)` if an internal error occurred
+    pub fn split_off(&mut self, at: usize) -> Result<List<T>, Errors> {
+        if at > self.len {
+            return Err(Errors::OutOfBounds);
+        } else if at == self.len {
+            return Ok(List::new());
+        } else if at == 0 {
+            let mut other = List::new();
+            other.append(self);
+            return Ok(other);
+        }
+
+        // First node of the returned list
+        let split_ptr = self._get_ptr_at_index(at).ok_or(Errors::InternalError)?;
+
+        // Sever the link between both halves
+        let new_end = unsafe { (*split_ptr).previous.take() };
+        if let Some(new_end_ptr) = new_end {
+            unsafe { (*new_end_ptr).next = None };
+        }
+
+        // The tail goes to the new list
+        let mut other = List::new();
+        other.start = Some(split_ptr);
+        other.end = self.end;
+        other.len = self.len - at;
+
+        // update bounds of the list
+        self.end = new_end;
+        self.len = at;
+        Ok(other)
+    }
+
+    /// Creates an iterator over mutable references paired with their index.
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding `(index, &mut T)` from front to back
+    pub fn iter_mut_indexed(&mut self) -> impl Iterator<Item = (usize, &mut T)> {
+        self.iter_mut().enumerate()
+    }
+
+    /// Reorders the list according to a permutation of its indices.
+    ///
+    /// After the call, the element that was at index `perm[i]` is at index `i`.
+    /// The nodes are relinked in the new order, no value is moved.
+    ///
+    /// # Arguments
+    ///
+    /// * `perm` - A permutation of `0..len`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the list was reordered
+    /// * `Err(Errors::InvalidPermutation)` if `perm` isn't a permutation of `0..len`,
+    ///   the list is left untouched
+    pub fn apply_permutation(&mut self, perm: &[usize]) -> Result<(), Errors> {
+        if perm.len() != self.len {
+            return Err(Errors::InvalidPermutation);
+        }
+
+        // Every index must appear exactly once
+        let mut seen = vec![false; self.len];
+        for &index in perm {
+            match seen.get_mut(index) {
+                Some(flag) if !*flag => *flag = true,
+                _ => return Err(Errors::InvalidPermutation),
+            }
+        }
+
+        // Snapshot the nodes in their current order
+        let mut nodes = Vec::with_capacity(self.len);
+        let mut current = self.start;
+        while let Some(ptr) = current {
+            nodes.push(ptr);
+            current = unsafe { (*ptr).next };
+        }
+
+        // Relink the nodes in the permuted order
+        let mut previous: Option<ItemPtr<T>> = None;
+        for &index in perm {
+            let ptr = nodes[index];
+            unsafe {
+                (*ptr).previous = previous;
+                (*ptr).next = None;
+                if let Some(previous_ptr) = previous {
+                    (*previous_ptr).next = Some(ptr);
+                }
+            }
+            previous = Some(ptr);
+        }
+
+        // update bounds of the list
+        self.start = perm.first().map(|&index| nodes[index]);
+        self.end = previous;
+        Ok(())
+    }
+
+    /// Removes the element at `index` by replacing it with the last element.
+    ///
+    /// This doesn't preserve the ordering of the list, but avoids relinking
+    /// nodes in the middle of it: the values are swapped, then the tail is popped.
+    /// Like `Vec::swap_remove`, the former last element ends up at `index`.
+    ///
+    /// # Parameters
+    ///
+    /// * `index` - The index of the element to remove
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(T)` containing the removed value
+    /// * `Err(Errors::OutOfBounds)` if the index is out of bounds
+    /// * `Err(Errors::InternalError)` if an internal error occurred
+    pub fn swap_remove(&mut self, index: usize) -> Result<T, Errors> {
+        let target_ptr = self._get_ptr_at_index(index).ok_or(Errors::OutOfBounds)?;
+        let end_ptr = self.end.ok_or(Errors::InternalError)?;
+
+        // Move the tail value into the target node
+        if target_ptr != end_ptr {
+            unsafe { std::ptr::swap(&mut (*target_ptr).value, &mut (*end_ptr).value) };
+        }
+
+        self.pop_back().ok_or(Errors::InternalError)
+    }
+
+    /// Removes the element at `index` by replacing it with the last element.
+    ///
+    /// This is the same as `swap_remove`.
+    ///
+    /// # Parameters
+    ///
+    /// * `index` - The index of the element to remove
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(T)` containing the removed value
+    /// * `Err(Errors::OutOfBounds)` if the index is out of bounds
+    /// * `Err(Errors::InternalError)` if an internal error occurred
+    pub fn checked_swap_remove(&mut self, index: usize) -> Result<T, Errors> {
+        self.swap_remove(index)
+    }
+
+    /// Relinks the list so that `new_start` becomes its first node.
+    ///
+    /// This is an internal helper method used by the rotations. The list is
+    /// closed into a ring and reopened right before `new_start`.
+    ///
+    /// # Parameters
+    ///
+    /// * `new_start` - A pointer to a node that belongs to this list
+    fn _rotate_to(&mut self, new_start: ItemPtr<T>) {
+        let (Some(old_start), Some(old_end)) = (self.start, self.end) else {
+            return;
+        };
+
+        // Already the first node
+        if old_start == new_start {
+            return;
+        }
+
+        unsafe {
+            // Close the ring
+            (*old_end).next = Some(old_start);
+            (*old_start).previous = Some(old_end);
+
+            // Open it right before the new start
+            let new_end = (*new_start).previous.take();
+            if let Some(new_end_ptr) = new_end {
+                (*new_end_ptr).next = None;
+            }
+            self.end = new_end;
+        }
+        self.start = Some(new_start);
+    }
+
+    /// Rotates the list left until its front element satisfies the predicate.
+    ///
+    /// The matching element is looked up in a single pass, then the list is
+    /// relinked so it becomes the front; no value is moved.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A predicate returning `true` for the element to bring to the front
+    ///
+    /// # Returns
+    ///
+    /// `true` if a matching element was found, `false` otherwise. When nothing
+    /// matches, a full cycle is equivalent to no rotation and the list is unchanged.
+    pub fn rotate_until_front<F>(&mut self, mut f: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut current = self.start;
+        while let Some(ptr) = current {
+            if f(unsafe { &(*ptr).value }) {
+                self._rotate_to(ptr);
+                return true;
+            }
+            current = unsafe { (*ptr).next };
+        }
+        false
+    }
+
+    /// Removes the elements in `range` and returns them as an iterator.
+    ///
+    /// The removed values are yielded front to back. When the iterator is
+    /// dropped, the elements of the range that weren't iterated are removed
+    /// as well, and the list is stitched back together around the range.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The range of indices to remove
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is decreasing or ends past the list length.
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = resolve_range(&range, self.len).expect("drain range out of bounds");
+        let current = self._get_ptr_at_index(start);
+
+        Drain {
+            list: self,
+            current,
+            remaining: end - start,
+        }
+    }
+
+    /// Combines this list with another one element by element.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The list providing the second operand of each pair
+    /// * `f` - A function combining two corresponding elements
+    ///
+    /// # Returns
+    ///
+    /// A new list holding `f(a, b)` for each pair, as long as the shorter list
+    pub fn zip_with<U, R, F>(&self, other: &List<U>, mut f: F) -> List<R>
+    where
+        F: FnMut(&T, &U) -> R,
+    {
+        self.iter()
+            .zip(other.iter())
+            .map(|(a, b)| f(a, b))
+            .collect()
+    }
+
+    /// Aggregates consecutive chunks of elements into a new list.
+    ///
+    /// The list is split into chunks of `size` elements, the last one possibly
+    /// shorter, and `f` is applied to the references of each chunk.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The number of elements per chunk
+    /// * `f` - A function reducing a chunk to a single value
+    ///
+    /// # Returns
+    ///
+    /// A new list holding one aggregated value per chunk
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn aggregate_chunks<B, F>(&self, size: usize, mut f: F) -> List<B>
+    where
+        F: FnMut(&[&T]) -> B,
+    {
+        assert!(size != 0, "chunk size must be non-zero");
+
+        let mut result = List::new();
+        // A chunk never holds more than the whole list, whatever `size` is
+        let mut chunk = Vec::with_capacity(size.min(self.len));
         for item in self.iter() {
-            new_list.push_back(item.clone());
+            chunk.push(item);
+            if chunk.len() == size {
+                result.push_back(f(&chunk));
+                chunk.clear();
+            }
+        }
+
+        // Keep the last partial chunk
+        if !chunk.is_empty() {
+            result.push_back(f(&chunk));
+        }
+
+        result
+    }
+
+    /// Swaps the contents of this list with another one in O(1).
+    ///
+    /// Only the bounds and lengths are exchanged, no node is touched.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The list to exchange contents with
+    pub fn swap_with(&mut self, other: &mut List<T>) {
+        std::mem::swap(self, other);
+    }
+
+    /// Finds the first element that is equal to an earlier element.
+    ///
+    /// The list is walked once, remembering the values seen so far.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((usize, &T))` with the index of the first repeated element
+    /// * `None` if all the elements are distinct
+    pub fn first_duplicate(&self) -> Option<(usize, &T)>
+    where
+        T: Hash + Eq,
+    {
+        let mut seen = HashSet::with_capacity(self.len);
+        self.iter()
+            .enumerate()
+            .find(|(_, item)| !seen.insert(*item))
+    }
+
+    /// Keeps at most `n` elements matching the predicate.
+    ///
+    /// The first `n` matching elements are kept, later matches are removed.
+    /// Elements that don't match the predicate are always kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The maximum number of matching elements to keep
+    /// * `f` - A predicate selecting the elements subject to the cap
+    pub fn retain_first_n<F>(&mut self, n: usize, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut kept = 0;
+        self.retain(|item| {
+            if !f(item) {
+                return true;
+            }
+            kept += 1;
+            kept <= n
+        });
+    }
+
+    /// Describes the link structure of the list without exposing pointers.
+    ///
+    /// The nodes are indexed in `next` order from the start, then each node's
+    /// `previous` and `next` pointers are translated into those indices. On a
+    /// well-formed list every tuple is `(i - 1, i, i + 1)`, with `None` past
+    /// either end, so tooling can verify the links externally.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` of `(prev_index, self_index, next_index)` tuples, in list order
+    pub fn collect_nodes(&self) -> Vec<(Option<usize>, usize, Option<usize>)> {
+        // Index every node reachable from the start
+        let mut nodes = Vec::with_capacity(self.len);
+        let mut indices = HashMap::with_capacity(self.len);
+        let mut current = self.start;
+        while let Some(ptr) = current {
+            indices.insert(ptr, nodes.len());
+            nodes.push(ptr);
+            current = unsafe { (*ptr).next };
         }
 
-        new_list
+        // Translate the links of each node into indices
+        let index_of = |link: Option<ItemPtr<T>>| link.and_then(|ptr| indices.get(&ptr).copied());
+        nodes
+            .iter()
+            .enumerate()
+            .map(|(index, &ptr)| unsafe {
+                (index_of((*ptr).previous), index, index_of((*ptr).next))
+            })
+            .collect()
+    }
+
+    /// Merges already-sorted lists into a single sorted list.
+    ///
+    /// The lists are merged pairwise until one remains, so this is
+    /// O(n log k) for `n` elements spread over `k` lists. The nodes are
+    /// relinked, no element is cloned or reallocated. The merge is stable:
+    /// on ties, elements of earlier lists come first.
+    ///
+    /// # Arguments
+    ///
+    /// * `lists` - The sorted lists to merge. Takes ownership of them.
+    /// * `f` - The comparison function the lists are sorted by
+    ///
+    /// # Returns
+    ///
+    /// A list containing every element, sorted according to `f`
+    pub fn merge_k_sorted_by<F>(lists: Vec<List<T>>, f: F) -> List<T>
+    where
+        F: FnMut(&T, &T) -> std::cmp::Ordering + Copy,
+    {
+        // Detach the node chains from their lists
+        let mut len = 0;
+        let mut chains = Vec::with_capacity(lists.len());
+        for mut list in lists {
+            if let (Some(new_start), Some(new_end)) = (list.start.take(), list.end.take()) {
+                len = checked_len_add(len, list.len);
+                chains.push(UpdateListBound { new_start, new_end });
+            }
+            list.len = 0;
+        }
+
+        // Merge neighbouring chains until a single one remains
+        while chains.len() > 1 {
+            let mut merged = Vec::with_capacity(chains.len().div_ceil(2));
+            let mut pairs = chains.into_iter();
+            while let Some(left) = pairs.next() {
+                match pairs.next() {
+                    Some(right) => merged.push(merge(
+                        left.new_start,
+                        left.new_end,
+                        right.new_start,
+                        right.new_end,
+                        f,
+                    )),
+                    None => merged.push(left),
+                }
+            }
+            chains = merged;
+        }
+
+        match chains.pop() {
+            Some(UpdateListBound { new_start, new_end }) => {
+                let mut list = List::new();
+                list.start = Some(new_start);
+                list.end = Some(new_end);
+                list.len = len;
+                list
+            }
+            None => List::new(),
+        }
+    }
+
+    /// Creates an iterator yielding elements from the front while a predicate holds.
+    ///
+    /// The iteration stops for good at the first element rejected by `pred`,
+    /// the rest of the list isn't visited.
+    ///
+    /// # Arguments
+    ///
+    /// * `pred` - A predicate checked against each element
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the leading elements matching `pred`
+    pub fn iter_while<F>(&self, mut pred: F) -> impl Iterator<Item = &T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().take_while(move |item| pred(item))
+    }
+
+    /// Creates an iterator yielding elements from the front until a predicate holds.
+    ///
+    /// The iteration stops for good at the first element matching `pred`,
+    /// which isn't yielded.
+    ///
+    /// # Arguments
+    ///
+    /// * `pred` - A predicate checked against each element
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the leading elements not matching `pred`
+    pub fn iter_until<F>(&self, mut pred: F) -> impl Iterator<Item = &T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().take_while(move |item| !pred(item))
+    }
+
+    /// Removes elements from the back of the list while a predicate holds.
+    ///
+    /// The elements are popped lazily, from the tail towards the front, so
+    /// the last element of the list is yielded first. Dropping the iterator
+    /// early leaves the matching elements that weren't yielded in the list.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A predicate checked against the current last element
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the removed trailing elements, tail first
+    pub fn drain_back_while<'a, F>(&'a mut self, mut f: F) -> impl Iterator<Item = T> + 'a
+    where
+        F: FnMut(&T) -> bool + 'a,
+    {
+        std::iter::from_fn(move || match self.last() {
+            Some(value) if f(value) => self.pop_back(),
+            _ => None,
+        })
+    }
+
+    /// Removes consecutive elements that are equal to their predecessor.
+    ///
+    /// The nodes are walked once and every duplicate is unlinked and freed,
+    /// so a sorted list ends up without any duplicate.
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|current, kept| current == kept);
+    }
+
+    /// Removes consecutive elements that the closure considers the same.
+    ///
+    /// Like `Vec::dedup_by`, `same` is called with each element and the last
+    /// kept element before it, in that order. When it returns `true` the
+    /// element is unlinked and freed. Both references are mutable, so the
+    /// closure can merge the removed element into the kept one.
+    ///
+    /// # Arguments
+    ///
+    /// * `same` - A closure receiving `(current, kept)`
+    pub fn dedup_by<F>(&mut self, mut same: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        let Some(mut kept) = self.start else {
+            return;
+        };
+
+        let mut current = unsafe { (*kept).next };
+        while let Some(ptr) = current {
+            // Save the next node before we possibly free the current one
+            current = unsafe { (*ptr).next };
+
+            // Distinct nodes, so the references don't alias
+            if same(unsafe { &mut (*ptr).value }, unsafe { &mut (*kept).value }) {
+                self._unlink_node(ptr);
+            } else {
+                kept = ptr;
+            }
+        }
+    }
+
+    /// Removes consecutive elements that resolve to the same key.
+    ///
+    /// The nodes are walked once, the first element of each run of equal keys
+    /// is kept and the others are unlinked and freed.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A closure extracting the key compared between neighbours
+    pub fn dedup_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: PartialEq,
+        F: FnMut(&T) -> K,
+    {
+        let Some(first) = self.start else {
+            return;
+        };
+
+        let mut kept_key = f(unsafe { &(*first).value });
+        let mut current = unsafe { (*first).next };
+        while let Some(ptr) = current {
+            // Save the next node before we possibly free the current one
+            current = unsafe { (*ptr).next };
+
+            let key = f(unsafe { &(*ptr).value });
+            if key == kept_key {
+                self._unlink_node(ptr);
+            } else {
+                kept_key = key;
+            }
+        }
+    }
+
+    /// Checks whether every given item is present in the list.
+    ///
+    /// The items are compared by value in any order, each one is looked up
+    /// with a linear scan so this is O(len * items.len()).
+    ///
+    /// # Parameters
+    ///
+    /// * `items` - The values to look for
+    ///
+    /// # Returns
+    ///
+    /// `true` if each item has an equal element in the list, `true` for an
+    /// empty slice, `false` otherwise
+    pub fn contains_all(&self, items: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        items.iter().all(|item| self.contains(item))
+    }
+
+    /// Checks whether at least one of the given items is present in the list.
+    ///
+    /// # Parameters
+    ///
+    /// * `items` - The values to look for
+    ///
+    /// # Returns
+    ///
+    /// `true` if any item has an equal element in the list, `false` otherwise
+    /// (including for an empty slice)
+    pub fn contains_any(&self, items: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|element| items.contains(element))
+    }
+
+    /// Checks whether every given item is present in the list using hashing.
+    ///
+    /// Behaves like `contains_all` but indexes the list elements in a
+    /// `HashSet` first, which is O(len + items.len()) for large inputs.
+    ///
+    /// # Parameters
+    ///
+    /// * `items` - The values to look for
+    ///
+    /// # Returns
+    ///
+    /// `true` if each item has an equal element in the list, `false` otherwise
+    pub fn contains_all_hashed(&self, items: &[T]) -> bool
+    where
+        T: Hash + Eq,
+    {
+        let elements = self.iter().collect::<HashSet<_>>();
+        items.iter().all(|item| elements.contains(item))
+    }
+
+    /// Consumes the list into an iterator yielding its elements back to front.
+    ///
+    /// This is a shorthand for `into_iter().rev()`, the list isn't reversed
+    /// beforehand.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the owned elements, from the last to the first
+    pub fn into_rev_iter(self) -> std::iter::Rev<IntoIter<T>> {
+        self.into_iter().rev()
+    }
+
+    /// Rewrites every `previous` pointer from the `next` chain.
+    ///
+    /// This is a recovery tool: the `next` links starting at `start` are taken
+    /// as the source of truth, each node's `previous` pointer is set to the
+    /// node walked right before it, then `end` and `len` are recomputed. It is
+    /// O(n) and leaves a well-formed list untouched.
+    pub fn rebuild_prev_links(&mut self) {
+        let mut previous = None;
+        let mut len = 0;
+
+        let mut current = self.start;
+        while let Some(ptr) = current {
+            unsafe { (*ptr).previous = previous };
+            previous = Some(ptr);
+            len += 1;
+            current = unsafe { (*ptr).next };
+        }
+
+        self.end = previous;
+        self.len = len;
+    }
+
+    /// Sets how many freed node allocations the list keeps for reuse.
+    ///
+    /// Removing an element normally returns its node to the allocator. With a
+    /// non-zero limit, up to `n` emptied nodes are kept and reused by the next
+    /// insertions, which saves allocator round-trips in push/pop heavy
+    /// workloads. The default limit is 0, nothing is recycled. Lowering the
+    /// limit releases the surplus right away.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The maximum number of recycled nodes to keep
+    pub fn set_recycle_limit(&mut self, n: usize) {
+        self.recycle_limit = n;
+        self.recycled.truncate(n);
+    }
+
+    /// Caps the number of freed nodes the list retains for reuse.
+    ///
+    /// After a burst of insertions, `truncate`, the pops and `clear` keep at
+    /// most `cap` emptied nodes, the others go back to the allocator. Nodes
+    /// already retained above the cap are freed right away. This is the same
+    /// setting as `set_recycle_limit`.
+    ///
+    /// # Arguments
+    ///
+    /// * `cap` - The maximum number of freed nodes to retain
+    pub fn set_max_pooled(&mut self, cap: usize) {
+        self.set_recycle_limit(cap);
+    }
+
+    /// Creates an iterator going once around the list from a given index.
+    ///
+    /// The iterator yields exactly `len` elements: from `start` to the back,
+    /// then wraps around to the front and stops right before `start`. An
+    /// out-of-range `start` is wrapped with a modulo.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The index of the first element to yield
+    ///
+    /// # Returns
+    ///
+    /// An iterator over every element, starting at `start % len`
+    pub fn iter_circular_from(&self, start: usize) -> impl Iterator<Item = &T> {
+        let start = start.checked_rem(self.len).unwrap_or(0);
+        self.iter().skip(start).chain(self.iter().take(start))
+    }
+
+    /// Removes consecutive elements sharing a key, keeping the largest of each run.
+    ///
+    /// The nodes are walked once. Within every run of equal keys, the element
+    /// with the largest `value` is kept and the others are unlinked and freed.
+    /// On ties the earliest element wins.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A closure extracting the key compared between neighbours
+    /// * `value` - A closure extracting the value compared within a run
+    pub fn dedup_by_key_keeping<K, V, FK, FV>(&mut self, mut key: FK, mut value: FV)
+    where
+        K: PartialEq,
+        V: Ord,
+        FK: FnMut(&T) -> K,
+        FV: FnMut(&T) -> V,
+    {
+        let Some(first) = self.start else {
+            return;
+        };
+
+        // The best node of the current run along with its key and value
+        let mut best = first;
+        let mut best_key = key(unsafe { &(*first).value });
+        let mut best_value = value(unsafe { &(*first).value });
+
+        let mut current = unsafe { (*first).next };
+        while let Some(ptr) = current {
+            // Save the next node before we possibly free the current one
+            current = unsafe { (*ptr).next };
+
+            let item = unsafe { &(*ptr).value };
+            let item_key = key(item);
+            let item_value = value(item);
+            if item_key != best_key {
+                // A new run starts
+                best = ptr;
+                best_key = item_key;
+                best_value = item_value;
+            } else if item_value > best_value {
+                self._unlink_node(best);
+                best = ptr;
+                best_value = item_value;
+            } else {
+                self._unlink_node(ptr);
+            }
+        }
+    }
+
+    /// Rotates the list left so the element at index `k` becomes the front.
+    ///
+    /// The new front is found by walking from the nearer end, then the list
+    /// is relinked around it, so this is O(min(k, len - k)) and no value is
+    /// moved. `k` is taken modulo the length, an empty list is left as is.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - The number of positions to rotate by
+    pub fn rotate_left(&mut self, k: usize) {
+        let Some(k) = k.checked_rem(self.len) else {
+            return;
+        };
+        if let Some(new_start) = self._get_ptr_at_index(k) {
+            self._rotate_to(new_start);
+        }
+    }
+
+    /// Rotates the list right so the last `k` elements move to the front.
+    ///
+    /// This is the inverse of `rotate_left`, with the same complexity. `k` is
+    /// taken modulo the length, an empty list is left as is.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - The number of positions to rotate by
+    pub fn rotate_right(&mut self, k: usize) {
+        let Some(k) = k.checked_rem(self.len) else {
+            return;
+        };
+        self.rotate_left(self.len - k);
+    }
+
+    /// Keeps only the first element of each distinct key across the whole list.
+    ///
+    /// Unlike `dedup_by_key`, duplicates don't have to be adjacent: the keys
+    /// seen so far are tracked in a `HashSet` during a single pass, and any
+    /// later element with an already seen key is unlinked and freed.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A closure extracting the key identifying each element
+    pub fn retain_unique_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: Hash + Eq,
+        F: FnMut(&T) -> K,
+    {
+        let mut seen = HashSet::with_capacity(self.len);
+        self.retain(|item| seen.insert(key(item)));
+    }
+
+    /// Shortens the list, keeping the first `len` elements.
+    ///
+    /// The new last node is found by walking from the nearer end, then every
+    /// node after it is freed. If `len` is greater than or equal to the list
+    /// length, this has no effect.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - The number of elements to keep
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        } else if len == 0 {
+            self.clear();
+            return;
+        }
+
+        let Some(new_end) = self._get_ptr_at_index(len - 1) else {
+            return;
+        };
+
+        // Detach the tail, then free it node by node
+        let mut current = unsafe { (*new_end).next.take() };
+        self.end = Some(new_end);
+        self.len = len;
+        while let Some(ptr) = current {
+            current = unsafe { (*ptr).next };
+            self._free_node(ptr);
+        }
+    }
+
+    /// Pops elements from the front until the list holds at most `max_len`.
+    ///
+    /// This is the eviction step of a sliding window. If `max_len` is greater
+    /// than or equal to the list length, nothing is removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_len` - The maximum number of elements to keep
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` with the removed elements, in their list order
+    pub fn trim_front_to(&mut self, max_len: usize) -> Vec<T> {
+        let excess = self.len.saturating_sub(max_len);
+        (0..excess).filter_map(|_| self.pop_front()).collect()
+    }
+
+    /// Splits the list into two mutable iterators around an index.
+    ///
+    /// The first iterator covers `[0, index)` and the second `[index, len)`.
+    /// The split point is found once by walking from the nearer end, and each
+    /// iterator is bounded to its own half, so they never alias and can be
+    /// used independently, including from different threads.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the first element of the second half
+    ///
+    /// # Returns
+    ///
+    /// * `Some((ListIterMut, ListIterMut))` with the two halves
+    /// * `None` if `index` is greater than the list length
+    pub fn split_at_mut(
+        &mut self,
+        index: usize,
+    ) -> Option<(ListIterMut<'_, T>, ListIterMut<'_, T>)> {
+        if index > self.len {
+            return None;
+        }
+
+        // First node of the second half, and last node of the first half
+        let split = if index == self.len {
+            None
+        } else {
+            Some(self._get_ptr_at_index(index)?)
+        };
+        let first_end = match split {
+            Some(ptr) => unsafe { (*ptr).previous },
+            None => self.end,
+        };
+
+        let first = ListIterMut {
+            left: first_end.and(self.start),
+            right: first_end,
+            remaining: index,
+            _phantom: PhantomData,
+        };
+        let second = ListIterMut {
+            left: split,
+            right: split.and(self.end),
+            remaining: self.len - index,
+            _phantom: PhantomData,
+        };
+        Some((first, second))
+    }
+
+    /// Creates an iterator over chunks of `size` elements.
+    ///
+    /// The chunks don't overlap and each one holds `size` references, except
+    /// the last one which may be shorter.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The number of elements in each chunk
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding a `Vec<&T>` per chunk, from front to back
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn chunks(&self, size: usize) -> Chunks<'_, T> {
+        assert!(size != 0, "chunk size must be non-zero");
+        Chunks {
+            iter: self.iter(),
+            size,
+        }
+    }
+
+    /// Creates an iterator over all the overlapping windows of `size` elements.
+    ///
+    /// Like `slice::windows`, each window starts one element after the
+    /// previous one. The iterator keeps the last `size` nodes in a buffer and
+    /// advances one node per window. If the list is shorter than `size`, no
+    /// window is yielded.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The number of elements in each window
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding a `Vec<&T>` per window, from front to back
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn windows(&self, size: usize) -> Windows<'_, T> {
+        assert!(size != 0, "window size must be non-zero");
+        // A list shorter than `size` has no window, skip walking it
+        let fits = size <= self.len;
+        Windows {
+            window: VecDeque::with_capacity(if fits { size } else { 0 }),
+            next: if fits { self.start } else { None },
+            size,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Creates a new list by applying a closure to each element reference.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A closure turning each element into an element of the new list
+    ///
+    /// # Returns
+    ///
+    /// A new `List<U>` holding the mapped values, in the same order
+    pub fn map<U, F>(&self, f: F) -> List<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        self.iter().map(f).collect()
+    }
+
+    /// Consumes the list into a new list by applying a closure to each element.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A closure turning each owned element into an element of the new list
+    ///
+    /// # Returns
+    ///
+    /// A new `List<U>` holding the mapped values, in the same order
+    pub fn map_into<U, F>(self, f: F) -> List<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        self.into_iter().map(f).collect()
+    }
+
+    /// Returns the first element satisfying the predicate.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicate` - A predicate returning `true` for the searched element
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` with the first match, from front to back
+    /// * `None` if no element satisfies the predicate
+    pub fn find<P>(&self, predicate: P) -> Option<&T>
+    where
+        P: FnMut(&&T) -> bool,
+    {
+        self.iter().find(predicate)
+    }
+
+    /// Returns the first non-`None` result of a closure applied to the elements.
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - A closure returning `Some` for the searched element
+    ///
+    /// # Returns
+    ///
+    /// * `Some(U)` with the first value returned by `f`, from front to back
+    /// * `None` if `f` returns `None` for every element
+    pub fn find_map<U, F>(&self, f: F) -> Option<U>
+    where
+        F: FnMut(&T) -> Option<U>,
+    {
+        self.iter().find_map(f)
+    }
+
+    /// Releases every recycled node allocation back to the allocator.
+    ///
+    /// The recycle pool is emptied but its limit is kept, so later removals
+    /// can fill it again. Live elements are left untouched.
+    pub fn shrink_to_fit(&mut self) {
+        self.recycled = Vec::new();
+    }
+
+    /// Collects references to every element into a `Vec`.
+    ///
+    /// The list is walked once, afterwards the snapshot gives O(1) indexed
+    /// access for as long as the list is borrowed.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` holding a reference to each element, in list order
+    pub fn to_ptr_vec(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
+
+    /// Checks whether the elements are sorted in ascending order.
+    ///
+    /// Adjacent pairs are compared from front to back, stopping at the first
+    /// pair out of order. Empty and single-element lists are sorted.
+    ///
+    /// # Returns
+    ///
+    /// `true` if every element is less than or equal to the next one
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.is_sorted_by(|a, b| a <= b)
+    }
+
+    /// Checks whether the elements are sorted according to a comparator.
+    ///
+    /// Adjacent pairs are compared from front to back, stopping at the first
+    /// pair for which `f` returns `false`.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A closure returning `true` if its two arguments are in order
+    ///
+    /// # Returns
+    ///
+    /// `true` if `f` holds for every pair of adjacent elements
+    pub fn is_sorted_by<F>(&self, mut f: F) -> bool
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        self.iter().zip(self.iter().skip(1)).all(|(a, b)| f(a, b))
+    }
+
+    /// Returns the smallest element of the list according to a comparator.
+    ///
+    /// Like `Iterator::min_by`, the first one is returned if several elements
+    /// are equally small.
+    ///
+    /// # Arguments
+    ///
+    /// * `compare` - A closure defining the order of the elements
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` with the smallest element
+    /// * `None` if the list is empty
+    pub fn min_by<F>(&self, mut compare: F) -> Option<&T>
+    where
+        F: FnMut(&T, &T) -> std::cmp::Ordering,
+    {
+        self.iter().min_by(|a, b| compare(a, b))
+    }
+
+    /// Returns the largest element of the list according to a comparator.
+    ///
+    /// Like `Iterator::max_by`, the last one is returned if several elements
+    /// are equally large.
+    ///
+    /// # Arguments
+    ///
+    /// * `compare` - A closure defining the order of the elements
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` with the largest element
+    /// * `None` if the list is empty
+    pub fn max_by<F>(&self, mut compare: F) -> Option<&T>
+    where
+        F: FnMut(&T, &T) -> std::cmp::Ordering,
+    {
+        self.iter().max_by(|a, b| compare(a, b))
+    }
+
+    /// Folds every element into an accumulator, from front to back.
+    ///
+    /// # Arguments
+    ///
+    /// * `init` - The initial value of the accumulator
+    /// * `f` - A closure combining the accumulator with each element
+    ///
+    /// # Returns
+    ///
+    /// The final accumulator, `init` for an empty list
+    pub fn fold<B, F>(&self, init: B, f: F) -> B
+    where
+        F: FnMut(B, &T) -> B,
+    {
+        self.iter().fold(init, f)
+    }
+
+    /// Reduces the elements to a single one by repeatedly applying a closure.
+    ///
+    /// The first element is cloned as the initial accumulator, then `f` is
+    /// called with the accumulator and each following element.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A closure combining the accumulator with each element
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` with the reduced value
+    /// * `None` if the list is empty
+    pub fn reduce<F>(&self, mut f: F) -> Option<T>
+    where
+        T: Clone,
+        F: FnMut(&T, &T) -> T,
+    {
+        let mut iter = self.iter();
+        let first = iter.next()?.clone();
+        Some(iter.fold(first, |accumulator, item| f(&accumulator, item)))
+    }
+
+    /// Sums the elements of the list.
+    ///
+    /// # Returns
+    ///
+    /// The sum of every element, as defined by `S: Sum<&T>`
+    pub fn sum<'a, S>(&'a self) -> S
+    where
+        S: std::iter::Sum<&'a T>,
+    {
+        self.iter().sum()
+    }
+
+    /// Multiplies the elements of the list.
+    ///
+    /// # Returns
+    ///
+    /// The product of every element, as defined by `P: Product<&T>`
+    pub fn product<'a, P>(&'a self) -> P
+    where
+        P: std::iter::Product<&'a T>,
+    {
+        self.iter().product()
+    }
+
+    /// Detaches the first node of the list without freeing it.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(DetachedNode<T>)` owning the first element and its allocation
+    /// * `None` if the list is empty
+    pub fn take_first(&mut self) -> Option<DetachedNode<T>> {
+        let ptr = self.start?;
+        self._detach_node(ptr);
+        Some(DetachedNode {
+            node: unsafe { Box::from_raw(ptr) },
+        })
+    }
+
+    /// Detaches the last node of the list without freeing it.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(DetachedNode<T>)` owning the last element and its allocation
+    /// * `None` if the list is empty
+    pub fn take_last(&mut self) -> Option<DetachedNode<T>> {
+        let ptr = self.end?;
+        self._detach_node(ptr);
+        Some(DetachedNode {
+            node: unsafe { Box::from_raw(ptr) },
+        })
+    }
+
+    /// Links a detached node at the front of the list.
+    ///
+    /// The node keeps its allocation, nothing is allocated or freed.
+    ///
+    /// # Parameters
+    ///
+    /// * `node` - A node detached from this list or another one
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list length would overflow `usize`.
+    pub fn push_front_node(&mut self, node: DetachedNode<T>) {
+        self.len = checked_len_add(self.len, 1);
+
+        let raw_ptr = Box::into_raw(node.node);
+        unsafe {
+            (*raw_ptr).previous = None;
+            (*raw_ptr).next = self.start;
+        }
+        match self.start.replace(raw_ptr) {
+            Some(previous_start) => unsafe { (*previous_start).previous = Some(raw_ptr) },
+            None => self.end = Some(raw_ptr),
+        }
+    }
+
+    /// Links a detached node at the back of the list.
+    ///
+    /// The node keeps its allocation, nothing is allocated or freed.
+    ///
+    /// # Parameters
+    ///
+    /// * `node` - A node detached from this list or another one
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list length would overflow `usize`.
+    pub fn push_back_node(&mut self, node: DetachedNode<T>) {
+        self.len = checked_len_add(self.len, 1);
+
+        let raw_ptr = Box::into_raw(node.node);
+        unsafe {
+            (*raw_ptr).next = None;
+            (*raw_ptr).previous = self.end;
+        }
+        match self.end.replace(raw_ptr) {
+            Some(previous_end) => unsafe { (*previous_end).next = Some(raw_ptr) },
+            None => self.start = Some(raw_ptr),
+        }
+    }
+
+    /// Creates an iterator over the maximal runs of related adjacent elements.
+    ///
+    /// Consecutive elements stay in the same group while `same` returns
+    /// `true` for each adjacent pair, like the nightly `slice::chunk_by`.
+    ///
+    /// # Arguments
+    ///
+    /// * `same` - A closure returning `true` if two adjacent elements belong
+    ///   to the same group
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding a non-empty `Vec<&T>` per group, from front to back
+    pub fn group_by<F>(&self, same: F) -> GroupBy<'_, T, F>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        GroupBy {
+            iter: self.iter(),
+            pending: None,
+            same,
+        }
+    }
+
+    /// Creates an iterator over chunks of `size` elements, starting at the back.
+    ///
+    /// The chunks don't overlap and are taken from the tail towards the
+    /// front, so the first one holds the last `size` elements and the final
+    /// one may be shorter. Each chunk keeps the list order.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The number of elements in each chunk
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding a `Vec<&T>` per chunk, from back to front
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn rchunks(&self, size: usize) -> RChunks<'_, T> {
+        assert!(size != 0, "chunk size must be non-zero");
+        RChunks {
+            iter: self.iter(),
+            size,
+        }
+    }
+
+    /// Creates a new list holding the elements of this list repeated `n` times.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of times the elements are repeated
+    ///
+    /// # Returns
+    ///
+    /// A new list with the cloned elements of this list, `n` times in sequence.
+    /// It is empty if `n` is 0 or if this list is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the new list would overflow `usize`.
+    pub fn repeat(&self, n: usize) -> List<T>
+    where
+        T: Clone,
+    {
+        let mut repeated = List::new();
+        if n == 0 || self.is_empty() {
+            return repeated;
+        }
+
+        // Fail before cloning anything, like `slice::repeat`
+        self.len.checked_mul(n).expect("list length overflow");
+        for _ in 0..n {
+            repeated.extend(self.iter().cloned());
+        }
+        repeated
+    }
+
+    /// Creates an iterator over the elements paired with their index.
+    ///
+    /// Unlike `iter().enumerate()`, the iterator is double-ended: indices
+    /// yielded from the back are the absolute indices of the elements, so
+    /// `next` and `next_back` can be mixed freely.
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding `(index, &T)`
+    pub fn iter_indexed(&self) -> IndexedIter<'_, T> {
+        IndexedIter {
+            iter: self.iter(),
+            front: 0,
+        }
+    }
+
+    /// Counts the elements satisfying the predicate.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicate` - A predicate returning `true` for the counted elements
+    ///
+    /// # Returns
+    ///
+    /// The number of elements for which `predicate` returned `true`
+    pub fn count_where<P>(&self, mut predicate: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter().filter(|item| predicate(item)).count()
+    }
+
+    /// Overwrites every element of the list with a clone of `value`.
+    ///
+    /// The values are replaced in a single forward pass, no node is relinked.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value every element is set to
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        for item in self.iter_mut() {
+            item.clone_from(&value);
+        }
+    }
+
+    /// Overwrites every element of the list with the values returned by `f`.
+    ///
+    /// `f` is called once per element, from front to back.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A closure producing the new values
+    pub fn fill_with<F>(&mut self, mut f: F)
+    where
+        F: FnMut() -> T,
+    {
+        for item in self.iter_mut() {
+            *item = f();
+        }
+    }
+
+    /// Formats the elements into a string, separated by `sep`.
+    ///
+    /// # Arguments
+    ///
+    /// * `sep` - The separator written between two elements
+    ///
+    /// # Returns
+    ///
+    /// A `String` with the displayed elements, from front to back
+    pub fn join(&self, sep: &str) -> String
+    where
+        T: Display,
+    {
+        let mut joined = String::new();
+        for (index, item) in self.iter().enumerate() {
+            if index > 0 {
+                joined.push_str(sep);
+            }
+            // Writing to a `String` never fails
+            let _ = write!(joined, "{}", item);
+        }
+        joined
+    }
+
+    /// Checks if both lists hold the same elements, regardless of their order.
+    ///
+    /// Elements are compared as a multiset: each value has to appear the same
+    /// number of times in both lists. Both lists are cloned into sorted
+    /// vectors, so this is O(n log n).
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The list to compare with
+    ///
+    /// # Returns
+    ///
+    /// `true` if the lists are equal once sorted, `false` otherwise
+    pub fn multiset_eq(&self, other: &List<T>) -> bool
+    where
+        T: Ord + Clone,
+    {
+        if self.len() != other.len() {
+            return false;
+        }
+
+        let mut left = self.iter().cloned().collect::<Vec<_>>();
+        let mut right = other.iter().cloned().collect::<Vec<_>>();
+        left.sort_unstable();
+        right.sort_unstable();
+        left == right
+    }
+
+    /// Returns a mutable reference to the first element, pushing one if the list is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A closure computing the new front, only called if the list is empty
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the existing or newly pushed first element
+    pub fn first_or_push_front_with<F>(&mut self, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        let ptr = match self.start {
+            Some(ptr) => ptr,
+            None => self._link_after(None, f()),
+        };
+        unsafe { &mut (*ptr).value }
+    }
+
+    /// Returns a mutable reference to the last element, pushing one if the list is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A closure computing the new back, only called if the list is empty
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the existing or newly pushed last element
+    pub fn last_or_push_back_with<F>(&mut self, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        let ptr = match self.end {
+            Some(ptr) => ptr,
+            None => self._link_before(None, f()),
+        };
+        unsafe { &mut (*ptr).value }
+    }
+
+    /// Returns a reference to the element at the specified index.
+    ///
+    /// Unlike `get`, a missing element is reported as an error, so it can be
+    /// propagated with `?` like the errors of `insert` or `swap_remove`.
+    ///
+    /// # Parameters
+    ///
+    /// * `index` - The index of the element to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(&T)` if the index is within bounds
+    /// * `Err(Errors::OutOfBounds)` if the index is out of bounds
+    pub fn try_get(&self, index: usize) -> Result<&T, Errors> {
+        self.get(index).ok_or(Errors::OutOfBounds)
+    }
+
+    /// Returns a mutable reference to the element at the specified index.
+    ///
+    /// This is the `Result` counterpart of `get_mut`.
+    ///
+    /// # Parameters
+    ///
+    /// * `index` - The index of the element to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(&mut T)` if the index is within bounds
+    /// * `Err(Errors::OutOfBounds)` if the index is out of bounds
+    pub fn try_get_mut(&mut self, index: usize) -> Result<&mut T, Errors> {
+        self.get_mut(index).ok_or(Errors::OutOfBounds)
+    }
+
+    /// Reverses the order of the elements within `range`, in place.
+    ///
+    /// The nodes of the range are relinked in reverse order and stitched back
+    /// between the unchanged prefix and suffix, no value is moved.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The range of indices to reverse
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the range was valid and reversed
+    /// * `Err(Errors::OutOfBounds)` if the range doesn't fit in the list
+    pub fn reverse_range<R>(&mut self, range: R) -> Result<(), Errors>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = resolve_range(&range, self.len).ok_or(Errors::OutOfBounds)?;
+
+        // Less than two elements, nothing to reverse
+        if end - start < 2 {
+            return Ok(());
+        }
+
+        let first = self._get_ptr_at_index(start).ok_or(Errors::InternalError)?;
+        let before = unsafe { (*first).previous };
+
+        // Swap the links of every node in the range, like `reverse` does
+        let mut current = Some(first);
+        let mut last = first;
+        for _ in start..end {
+            let ptr = current.ok_or(Errors::InternalError)?;
+
+            // Save the next node before we change any pointers
+            current = unsafe { (*ptr).next };
+            unsafe { std::mem::swap(&mut (*ptr).next, &mut (*ptr).previous) };
+            last = ptr;
+        }
+        let after = current;
+
+        // Stitch the reversed segment back between the prefix and the suffix
+        unsafe {
+            (*first).next = after;
+            (*last).previous = before;
+            match before {
+                Some(before_ptr) => (*before_ptr).next = Some(last),
+                None => self.start = Some(last),
+            }
+            match after {
+                Some(after_ptr) => (*after_ptr).previous = Some(first),
+                None => self.end = Some(first),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Swaps the values of the first and the last elements in O(1).
+    ///
+    /// Only the values are swapped, the nodes stay linked in place. Lists
+    /// with fewer than two elements are left untouched.
+    pub fn swap_ends(&mut self) {
+        if let (Some(start), Some(end)) = (self.start, self.end) {
+            if start != end {
+                unsafe { std::ptr::swap(&mut (*start).value, &mut (*end).value) };
+            }
+        }
+    }
+}
+
+impl<T: Ord> List<T> {
+    /// Sorts the list in ascending order.
+    ///
+    /// This method sorts the elements of the list in place, using their natural ordering.
+    /// Elements must implement the `Ord` trait to use this method.
+    ///
+    /// The sort operation is stable: equal elements retain their relative order.
+    /// The algorithm has O(n log n) time complexity and uses a merge sort implementation.
+    pub fn sort(&mut self) {
+        self.sort_by(|a, b| a.cmp(b));
+    }
+
+    /// Returns the smallest element of the list.
+    ///
+    /// Like `Iterator::min`, the first one is returned if several elements
+    /// are equally small. It isn't named `min` since `Ord::min` would take
+    /// precedence, comparing whole lists.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` with the smallest element
+    /// * `None` if the list is empty
+    pub fn min_element(&self) -> Option<&T> {
+        self.iter().min()
+    }
+
+    /// Returns the largest element of the list.
+    ///
+    /// Like `Iterator::max`, the last one is returned if several elements
+    /// are equally large. It isn't named `max` since `Ord::max` would take
+    /// precedence, comparing whole lists.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` with the largest element
+    /// * `None` if the list is empty
+    pub fn max_element(&self) -> Option<&T> {
+        self.iter().max()
+    }
+
+    /// Returns both the smallest and the largest element in a single pass.
+    ///
+    /// The elements are compared in pairs, then the smaller one against the
+    /// current minimum and the larger one against the current maximum, which
+    /// takes about 1.5 comparisons per element. Like `Iterator::min` and
+    /// `Iterator::max`, the first minimum and the last maximum are returned.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((&T, &T))` with `(min, max)`, both the same element for a
+    ///   single-element list
+    /// * `None` if the list is empty
+    pub fn min_max(&self) -> Option<(&T, &T)> {
+        let mut iter = self.iter();
+        let first = iter.next()?;
+        let (mut min, mut max) = (first, first);
+
+        while let Some(a) = iter.next() {
+            // Order the pair first, the odd element is compared on both sides
+            let (small, large) = match iter.next() {
+                Some(b) if b < a => (b, a),
+                Some(b) => (a, b),
+                None => (a, a),
+            };
+            if small < min {
+                min = small;
+            }
+            if large >= max {
+                max = large;
+            }
+        }
+        Some((min, max))
+    }
+
+    /// Inserts a value into a sorted list, keeping it sorted.
+    ///
+    /// The list is walked from the front and the value is linked right before
+    /// the first element greater than or equal to it, or at the back if there
+    /// is none. The list is assumed to be sorted in ascending order.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The value to insert
+    ///
+    /// # Returns
+    ///
+    /// The index the value was inserted at
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list length would overflow `usize`.
+    pub fn insert_sorted(&mut self, value: T) -> usize {
+        let mut index = 0;
+        let mut current = self.start;
+        while let Some(ptr) = current {
+            if &value <= unsafe { &(*ptr).value } {
+                break;
+            }
+            current = unsafe { (*ptr).next };
+            index += 1;
+        }
+
+        // Past the last element, the anchor is `None` and the value goes to the back
+        self._link_before(current, value);
+        index
+    }
+
+    /// Binary searches a sorted list for the given element.
+    ///
+    /// The search range is halved at each step like `slice::binary_search`,
+    /// but reaching the midpoint means walking from the start of the current
+    /// range. This takes O(log n) comparisons and O(n) pointer steps in
+    /// total, which pays off when comparing elements is expensive.
+    ///
+    /// # Parameters
+    ///
+    /// * `target` - The value to look for
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` with the index of a matching element, any of them if
+    ///   there are several
+    /// * `Err(usize)` with the index where `target` could be inserted while
+    ///   keeping the list sorted
+    pub fn binary_search(&self, target: &T) -> Result<usize, usize> {
+        let Some(mut base_ptr) = self.start else {
+            return Err(0);
+        };
+
+        let mut base = 0;
+        let mut size = self.len;
+        while size > 1 {
+            // Walk to the midpoint of the current range
+            let half = size / 2;
+            let mut mid_ptr = base_ptr;
+            for _ in 0..half {
+                mid_ptr = unsafe { (*mid_ptr).next.unwrap() };
+            }
+
+            // Keep the upper half unless the midpoint is past the target
+            if unsafe { &(*mid_ptr).value } <= target {
+                base += half;
+                base_ptr = mid_ptr;
+            }
+            size -= half;
+        }
+
+        match unsafe { (*base_ptr).value.cmp(target) } {
+            std::cmp::Ordering::Equal => Ok(base),
+            std::cmp::Ordering::Less => Err(base + 1),
+            std::cmp::Ordering::Greater => Err(base),
+        }
+    }
+}
+
+// SAFETY: the list exclusively owns its nodes, the raw pointers are never
+// shared with another list. Sending the list sends its `T` values along.
+unsafe impl<T: Send> Send for List<T> {}
+
+// SAFETY: a shared list only hands out `&T`, so sharing it across threads
+// is sound whenever sharing `T` is.
+unsafe impl<T: Sync> Sync for List<T> {}
+
+impl<T> Drop for List<T> {
+    fn drop(&mut self) {
+        // Start on the begin ptr and iterate until we arrive to the end
+        let mut ptr_opt = self.start;
+
+        // Iterate
+        while let Some(ptr) = ptr_opt {
+            // get next
+            let next = unsafe { (*ptr).next };
+
+            // Drop the node (taking ownership with a box will drop it)
+            // At the end of the iteration
+            unsafe {
+                let _ = Box::from_raw(ptr);
+            }
+
+            // Move to next
+            ptr_opt = next;
+        }
+
+        // Cls other ptrs
+        self.start = None;
+        self.end = None;
+    }
+}
+
+impl<A> FromIterator<A> for List<A> {
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        let mut new_list = List::new();
+
+        // Track the tail locally, the start is set once and the end at the finish
+        let mut tail: Option<ItemPtr<A>> = None;
+        for item in iter {
+            new_list.len = checked_len_add(new_list.len, 1);
+            let raw_ptr = new_list._alloc_node(DoubleLinkedListItem {
+                value: item,
+                next: None,
+                previous: tail,
+            });
+            match tail {
+                Some(tail_ptr) => unsafe { (*tail_ptr).next = Some(raw_ptr) },
+                None => new_list.start = Some(raw_ptr),
+            }
+            tail = Some(raw_ptr);
+        }
+        new_list.end = tail;
+
+        new_list
+    }
+}
+
+impl<T> From<Vec<T>> for List<T> {
+    fn from(vec: Vec<T>) -> Self {
+        let mut new_list = List::new();
+
+        // Move each value of the vector at the end of the new list
+        for item in vec {
+            new_list.push_back(item);
+        }
+
+        new_list
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for List<T> {
+    fn from(array: [T; N]) -> Self {
+        let mut new_list = List::new();
+
+        // Move each value of the array at the end of the new list
+        for item in array {
+            new_list.push_back(item);
+        }
+
+        new_list
+    }
+}
+
+impl<T> From<List<T>> for Vec<T> {
+    fn from(list: List<T>) -> Self {
+        let mut vec = Vec::with_capacity(list.len());
+
+        // Drain the list from the front
+        vec.extend(list);
+
+        vec
+    }
+}
+
+impl<T: Clone> Clone for List<T> {
+    fn clone(&self) -> Self {
+        let mut new_list = List::new();
+
+        // Iterate through original list and add each value to the new list
+        for item in self.iter() {
+            new_list.push_back(item.clone());
+        }
+
+        new_list
+    }
+
+    /// Overwrites this list with a clone of `source`.
+    ///
+    /// The list is cleared first, so its nodes go to its recycle pool and
+    /// are reused for the cloned elements, up to the recycle limit. The
+    /// recycle limit of this list is kept. `ToOwned::clone_into` relies on
+    /// this method as well.
+    fn clone_from(&mut self, source: &Self) {
+        self.clear();
+        for item in source.iter() {
+            self.push_back(item.clone());
+        }
+    }
+}
+
+impl<T> Index<usize> for List<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).expect("Index out of bounds")
+    }
+}
+
+impl<T> IndexMut<usize> for List<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.get_mut(index).expect("Index out of bounds")
+    }
+}
+
+impl<T: Debug> Debug for List<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Display> Display for List<T> {
+    /// Formats the elements separated by `", "`, without brackets.
+    ///
+    /// Use `join` for another separator.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, item) in self.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            Display::fmt(item, f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Extend<T> for List<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push_back(item);
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for List<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push_back(*item);
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for List<T> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+
+        self.iter().zip(other.iter()).all(|(a, b)| a == b)
+    }
+}
+
+impl<T: PartialEq> PartialEq<[T]> for List<T> {
+    fn eq(&self, other: &[T]) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+
+        self.iter().zip(other.iter()).all(|(a, b)| a == b)
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<[T; N]> for List<T> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<T: PartialEq> PartialEq<Vec<T>> for List<T> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<T: PartialEq> PartialEq<List<T>> for [T] {
+    fn eq(&self, other: &List<T>) -> bool {
+        *other == *self
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<List<T>> for [T; N] {
+    fn eq(&self, other: &List<T>) -> bool {
+        *other == self[..]
+    }
+}
+
+impl<T: PartialEq> PartialEq<List<T>> for Vec<T> {
+    fn eq(&self, other: &List<T>) -> bool {
+        *other == self[..]
+    }
+}
+
+impl<T: Eq> Eq for List<T> {}
+
+impl<T: PartialOrd> PartialOrd for List<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        // Lexicographic, a shorter prefix comes first
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for List<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // Lexicographic, a shorter prefix comes first
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T> Add for List<T> {
+    type Output = List<T>;
+
+    /// Concatenates `rhs` onto the end of this list in O(1).
+    fn add(mut self, rhs: List<T>) -> Self::Output {
+        self.concatenate(rhs);
+        self
+    }
+}
+
+impl<T> AddAssign for List<T> {
+    /// Moves all the elements of `rhs` to the end of this list in O(1).
+    fn add_assign(&mut self, rhs: List<T>) {
+        self.concatenate(rhs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constructor_test() {
+        // Arrange
+        let list = List::<i32>::new();
+
+        // Arrange
+        assert!(list.is_empty());
+        assert_eq!(list.iter().count(), 0);
+    }
+
+    #[test]
+    fn push_back_test() {
+        // Arrange
+        let mut list = List::new();
+
+        // Act
+        list.push_back(1);
+        list.push_back(2);
+        let mut iter = list.iter();
+
+        // Arrange
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.iter().count(), 2);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn push_front_test() {
+        // Arrange
+        let mut list = List::new();
+
+        // Act
+        list.push_front(1);
+        list.push_front(2);
+        let mut iter = list.iter();
+
+        // Arrange
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.iter().count(), 2);
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn pop_front_test() {
+        // Arrange
+        let mut list = List::new();
+        list.extend(0..5);
+
+        // Arrange
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.iter().count(), 5);
+        assert_eq!(list.pop_front(), Some(0));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_front(), Some(3));
+        assert_eq!(list.pop_front(), Some(4));
+    }
+
+    #[test]
+    fn pop_back_test() {
+        // Arrange
+        let mut list = List::new();
+        list.extend((0..5).rev());
+
+        // Arrange
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.pop_back(), Some(0));
+        assert_eq!(list.pop_back(), Some(1));
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_back(), Some(4));
+    }
+
+    #[test]
+    fn insert_empty_test() {
+        // Arrange
+        let mut list = List::new();
+        let _ = list.insert(0, 1);
+
+        // Assert
+        assert_eq!(list[0], 1);
+    }
+
+    #[test]
+    fn insert_push_front_test() {
+        // Arrange
+        let mut list = List::new();
+        list.push_front(1);
+        let _ = list.insert(0, 0);
+
+        // Assert
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.iter().count(), 2);
+        for (expected, real) in list.iter().zip(0..2) {
+            assert_eq!(expected, &real);
+        }
+    }
+
+    #[test]
+    fn insert_push_back_test() {
+        // Arrange
+        let mut list = List::new();
+        list.push_front(1);
+        let _ = list.insert(1, 2);
+
+        // Assert
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.iter().count(), 2);
+        for (expected, real) in list.iter().zip(1..3) {
+            assert_eq!(expected, &real);
+        }
+    }
+
+    #[test]
+    fn insert_test() {
+        // Arrange
+        let mut list = List::new();
+        list.push_back(1);
+        list.push_back(3);
+        let _ = list.insert(1, 2);
+
+        // Assert
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.iter().count(), 3);
+        for (expected, real) in list.iter().zip(1..4) {
+            assert_eq!(expected, &real);
+        }
+    }
+
+    #[test]
+    fn insert_out_of_bound_test() {
+        // Arrange
+        let mut list = List::new();
+        list.push_back(1);
+        list.push_back(3);
+        let result = list.insert(3, 2);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn reverse_test() {
+        // Arrange
+        let mut list = List::new();
+        list.extend(0..5);
+
+        // Assert
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.iter().count(), 5);
+        for (expected, real) in list.iter().zip(0..5) {
+            assert_eq!(expected, &real);
+        }
+
+        // Act
+        list.reverse();
+
+        // Assert
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.iter().count(), 5);
+        let mut other = (0..5).collect::<Vec<_>>();
+        other.reverse();
+        for (expected, real) in list.iter().zip(other) {
+            assert_eq!(expected, &real);
+        }
+    }
+
+    #[test]
+    fn clear_test() {
+        // Arrange
+        let mut list = List::new();
+        list.extend(0..5);
+        list.clear();
+
+        // assert
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.iter().count(), 0);
+    }
+
+    #[test]
+    fn first_last_same_test() {
+        // Arrange
+        let mut list = List::new();
+        list.push_back(1);
+
+        // assert
+        assert_eq!(list.first(), Some(&1));
+        assert_eq!(list.last(), Some(&1));
+        assert_eq!(list.iter().count(), 1);
+    }
+
+    #[test]
+    fn first_last_different_test() {
+        // Arrange
+        let mut list = List::new();
+        list.push_back(1);
+        list.push_back(2);
+
+        // assert
+        assert_eq!(list.first(), Some(&1));
+        assert_eq!(list.last(), Some(&2));
+        assert_eq!(list.iter().count(), 2);
+    }
+
+    #[test]
+    fn first_last_empty_test() {
+        // Arrange
+        let list = List::<i32>::new();
+
+        // assert
+        assert_eq!(list.first(), None);
+        assert_eq!(list.last(), None);
+        assert_eq!(list.iter().count(), 0);
+    }
+
+    #[test]
+    fn from_iterator_test() {
+        // Arrange
+        let list = (0..5).collect::<List<_>>();
+
+        // assert
+        assert_eq!(list.first(), Some(&0));
+        assert_eq!(list.last(), Some(&4));
+        assert_eq!(list.iter().count(), 5);
+        assert_eq!(list.len(), 5);
+    }
+
+    #[test]
+    fn clone_add_test() {
+        // Arrange
+        let mut list = List::new();
+        list.push_back(0);
+        list.push_back(1);
+        let other_list = list.clone();
+        list.push_back(2);
+
+        // assert
+        assert_eq!(list.first(), other_list.first());
+        assert_ne!(list.last(), other_list.last());
+        assert_eq!(list.len(), other_list.len() + 1);
+        assert_eq!(list.iter().count(), other_list.iter().count() + 1);
+    }
+
+    #[test]
+    fn partial_eq_test() {
+        // Arrange
+        let list1 = (0..5).collect::<List<_>>();
+        let list2 = (0..5).collect::<List<_>>();
+
+        // Assert
+        assert_eq!(list1, list2);
+    }
+
+    #[test]
+    fn partial_eq_not_same_len_test() {
+        // Arrange
+        let list1 = (0..5).collect::<List<_>>();
+        let list2 = (0..4).collect::<List<_>>();
+
+        // Assert
+        assert_ne!(list1, list2);
+    }
+
+    #[test]
+    fn partial_eq_not_same_value_test() {
+        // Arrange
+        let list1 = (0..5).collect::<List<_>>();
+        let list2 = (1..6).collect::<List<_>>();
+
+        // Assert
+        assert_ne!(list1, list2);
+    }
+
+    #[test]
+    fn fmt_test() {
+        // Arrange
+        let list = (0..2).collect::<List<_>>();
+
+        // Convert the debug representation to a string
+        let debug_output = format!("{:?}", list);
+
+        // Assert
+        assert_eq!("[0, 1]", debug_output);
+    }
+
+    #[test]
+    fn get_index_test() {
+        // Arrange
+        let list = (0..2).collect::<List<_>>();
+
+        // Assert
+        assert_eq!(0, list[0]);
+        assert_eq!(Some(&0), list.get(0));
+        assert_eq!(1, list[1]);
+        assert_eq!(Some(&1), list.get(1));
+        assert_eq!(None, list.get(2));
+    }
+
+    #[test]
+    fn get_mut_test() {
+        // Arrange
+        let mut list = (0..2).collect::<List<_>>();
+
+        // Act & Assert
+        list[0] = 1;
+        assert_eq!(1, list[0]);
+        if let Some(v) = list.get_mut(1) {
+            *v += 1;
+        }
+        assert_eq!(2, list[1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_panic_out_of_bounds_test() {
+        // Arrange
+        let list = (0..2).collect::<List<_>>();
+
+        // Assert
+        let _ = list[2];
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_mut_panic_out_of_bounds_test() {
+        // Arrange
+        let mut list = (0..2).collect::<List<_>>();
+
+        // Assert
+        list[2] = 2;
+    }
+
+    #[test]
+    fn index_get_from_end_test() {
+        // Arrange
+        let list = (0..5).collect::<List<_>>();
+
+        // Assert
+        assert_eq!(list.get(3), Some(&3));
+    }
+
+    #[test]
+    fn reverse_empty_test() {
+        // Arrange
+        let mut list = List::<i32>::new();
+        list.reverse();
+
+        // Assert
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn concatenate_left_empty_test() {
+        // Arrange
+        let mut list = List::<i32>::new();
+        let other_list = (0..5).collect::<List<_>>();
+        let clone = other_list.clone();
+        list.concatenate(other_list);
+
+        // Assert
+        assert_eq!(list, clone);
+    }
+
+    #[test]
+    fn concatenate_right_empty_test() {
+        // Arrange
+        let mut list = (0..5).collect::<List<_>>();
+        let other_list = List::<i32>::new();
+        let clone = list.clone();
+        list.concatenate(other_list);
+
+        // Assert
+        assert_eq!(list, clone);
+    }
+
+    #[test]
+    fn concatenate_test() {
+        // Arrange
+        let mut list = (0..3).collect::<List<_>>();
+        let other_list = (3..5).collect::<List<_>>();
+        list.concatenate(other_list);
+
+        // Assert
+        assert_eq!(list, (0..5).collect::<List<_>>());
+    }
+
+    #[test]
+    fn sort_by_test() {
+        // Arrange
+        let mut list = (0..3).collect::<List<_>>();
+        let mut list2 = (3..8).collect::<List<_>>();
+        list2.reverse();
+        list.concatenate(list2);
+
+        list.sort_by(|a, b| b.cmp(a));
+
+        // Assert
+        assert!(list.iter().is_sorted_by(|a, b| a > b));
+        assert_eq!(list, (0..8).rev().collect::<List<_>>());
+    }
+
+    #[test]
+    fn sort_test() {
+        // Arrange
+        let mut list = (0..3).collect::<List<_>>();
+        let mut list2 = (3..8).collect::<List<_>>();
+        list2.reverse();
+        list.concatenate(list2);
+
+        list.sort();
+
+        // Assert
+        assert!(list.iter().is_sorted_by(|a, b| a < b));
+        assert_eq!(list, (0..8).collect::<List<_>>());
+    }
+
+    #[test]
+    fn sort_empty_test() {
+        // Arrange
+        let mut list = (0..1).collect::<List<_>>();
+        list.sort();
+
+        // Assert
+        assert_eq!(list.len(), 1);
+        assert_eq!(list, (0..1).collect::<List<_>>());
+    }
+
+    #[test]
+    fn retain_range_test() {
+        // Arrange
+        let mut list = (0..6).collect::<List<_>>();
+
+        // Act
+        let result = list.retain_range(1..5, |v| v % 2 == 0);
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.iter().count(), 4);
+        assert_eq!(list, List::from_iter([0, 2, 4, 5]));
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![5, 4, 2, 0]
+        );
+    }
+
+    #[test]
+    fn retain_range_bounds_test() {
+        // Arrange
+        let mut list = (0..6).collect::<List<_>>();
+
+        // Act
+        list.retain_range(.., |v| *v > 3).unwrap();
+
+        // Assert
+        assert_eq!(list, List::from_iter([4, 5]));
+        assert_eq!(list.first(), Some(&4));
+        assert_eq!(list.last(), Some(&5));
+    }
+
+    #[test]
+    fn retain_range_out_of_bounds_test() {
+        // Arrange
+        let mut list = (0..6).collect::<List<_>>();
+
+        // Assert
+        assert!(list.retain_range(4..7, |_| false).is_err());
+        assert!(list.retain_range(7.., |_| false).is_err());
+        assert_eq!(list, (0..6).collect::<List<_>>());
+    }
+
+    #[test]
+    fn sort_descending_test() {
+        // Arrange
+        let mut list = (0..10).rev().collect::<List<_>>();
+
+        // Act
+        list.sort();
+
+        // Assert
+        assert_eq!(list.len(), 10);
+        assert_eq!(list, (0..10).collect::<List<_>>());
+        assert_eq!(list.first(), Some(&0));
+        assert_eq!(list.last(), Some(&9));
+    }
+
+    #[test]
+    fn sort_by_key_test() {
+        // Arrange
+        let mut list = List::from_iter(["three", "a", "go", "four"].map(String::from));
+
+        // Act
+        list.sort_by_key(|s| s.len());
+
+        // Assert
+        assert_eq!(
+            list.iter().map(String::as_str).collect::<Vec<_>>(),
+            vec!["a", "go", "four", "three"]
+        );
+    }
+
+    #[test]
+    fn fold_while_test() {
+        // Arrange
+        let list = (1..5).collect::<List<_>>();
+        let mut visited = 0;
+
+        // Act
+        let sum = list.fold_while(0, |acc, v| {
+            visited += 1;
+            let acc = acc + v;
+            if acc > 5 {
+                ControlFlow::Break(acc)
+            } else {
+                ControlFlow::Continue(acc)
+            }
+        });
+
+        // Assert
+        assert_eq!(sum, 6);
+        assert_eq!(visited, 3);
+    }
+
+    #[test]
+    fn fold_while_no_break_test() {
+        // Arrange
+        let list = (1..5).collect::<List<_>>();
+
+        // Act
+        let sum = list.fold_while(0, |acc, v| ControlFlow::Continue(acc + v));
+
+        // Assert
+        assert_eq!(sum, 10);
+    }
+
+    #[test]
+    fn iter_clone_test() {
+        // Arrange
+        let list = (0..5).collect::<List<_>>();
+        let mut iter = list.iter();
+        iter.next();
+
+        // Act
+        let mut snapshot = Clone::clone(&iter);
+        let copied = iter;
+
+        // Assert
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(snapshot.next(), Some(&1));
+        assert_eq!(snapshot.next_back(), Some(&4));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![&3, &4]);
+        assert_eq!(snapshot.collect::<Vec<_>>(), vec![&2, &3]);
+        assert_eq!(copied.count(), 4);
+    }
+
+    #[test]
+    fn iter_mut_test() {
+        // Arrange
+        let mut list = (0..4).collect::<List<_>>();
+
+        // Act
+        for value in list.iter_mut() {
+            *value *= 10;
+        }
+        if let Some(last) = list.iter_mut().next_back() {
+            *last += 1;
+        }
+
+        // Assert
+        assert_eq!(list, List::from_iter([0, 10, 20, 31]));
+    }
+
+    #[test]
+    fn iter_mut_into_iter_ref_test() {
+        // Arrange
+        let mut list = (0..5).collect::<List<_>>();
+        let mut iter = list.iter_mut();
+
+        // Act
+        for value in iter.by_ref().take(2) {
+            *value += 100;
+        }
+        *iter.next_back().unwrap() += 100;
+        let remaining = iter.into_iter_ref();
+
+        // Assert
+        assert_eq!(remaining.collect::<Vec<_>>(), vec![&2, &3]);
+        assert_eq!(list, List::from_iter([100, 101, 2, 3, 104]));
+    }
+
+    #[test]
+    fn ord_prefix_test() {
+        // Arrange
+        let short = List::from_iter([1, 2]);
+        let long = List::from_iter([1, 2, 3]);
+
+        // Assert
+        assert!(short < long);
+        assert_eq!(long.cmp(&short), std::cmp::Ordering::Greater);
+        assert!(List::<i32>::new() < short);
+    }
+
+    #[test]
+    fn ord_equal_test() {
+        // Arrange
+        let list1 = (0..5).collect::<List<_>>();
+        let list2 = (0..5).collect::<List<_>>();
+
+        // Assert
+        assert_eq!(list1.cmp(&list2), std::cmp::Ordering::Equal);
+        assert_eq!(list1.partial_cmp(&list2), Some(std::cmp::Ordering::Equal));
+    }
+
+    #[test]
+    fn ord_first_difference_test() {
+        // Arrange
+        let list1 = List::from_iter([1, 3]);
+        let list2 = List::from_iter([1, 2, 9]);
+        let mut lists = vec![list1.clone(), list2.clone()];
+
+        // Act
+        lists.sort();
+
+        // Assert
+        assert!(list1 > list2);
+        assert_eq!(lists, vec![list2, list1]);
+        assert_eq!(
+            List::from_iter([1.0, f64::NAN]).partial_cmp(&List::from_iter([1.0, 2.0])),
+            None
+        );
+    }
+
+    #[test]
+    fn splice_at_test() {
+        // Arrange
+        let mut list = (0..4).collect::<List<_>>();
+
+        // Act
+        let result = list.splice_at(2, List::from_iter([9, 9]));
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(list.len(), 6);
+        assert_eq!(list, List::from_iter([0, 1, 9, 9, 2, 3]));
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![3, 2, 9, 9, 1, 0]
+        );
+    }
+
+    #[test]
+    fn splice_at_ends_test() {
+        // Arrange
+        let mut list = List::from_iter([1, 2]);
+
+        // Act
+        list.splice_at(0, List::from_iter([-1, 0])).unwrap();
+        list.splice_at(4, List::from_iter([3])).unwrap();
+        list.splice_at(2, List::new()).unwrap();
+
+        // Assert
+        assert_eq!(list, (-1..4).collect::<List<_>>());
+        assert_eq!(list.first(), Some(&-1));
+        assert_eq!(list.last(), Some(&3));
+        assert!(list.splice_at(7, List::from_iter([0])).is_err());
+    }
+
+    #[test]
+    fn contains_test() {
+        // Arrange
+        let list = (0..5).collect::<List<_>>();
+
+        // Assert
+        assert!(list.contains(&2));
+        assert!(!list.contains(&5));
+        assert!(!List::<i32>::new().contains(&0));
+    }
+
+    #[test]
+    fn position_test() {
+        // Arrange
+        let list = List::from_iter([4, 7, 1, 7, 3]);
+
+        // Assert
+        assert_eq!(list.position(|v| *v == 1), Some(2));
+        assert_eq!(list.position(|v| *v == 7), Some(1));
+        assert_eq!(list.position(|v| *v > 10), None);
+    }
+
+    #[test]
+    fn run_stats_test() {
+        // Arrange
+        let list = List::from_iter([1, 1, 2, 3, 3, 3]);
+
+        // Assert
+        assert_eq!(list.run_stats(), vec![(0, 2), (2, 1), (3, 3)]);
+        assert_eq!(list.len(), 6);
+        assert!(List::<i32>::new().run_stats().is_empty());
+    }
+
+    #[test]
+    fn retain_test() {
+        // Arrange
+        let mut list = (0..10).collect::<List<_>>();
+
+        // Act
+        list.retain(|v| v % 2 == 0);
+
+        // Assert
+        assert_eq!(list.len(), 5);
+        assert_eq!(list, List::from_iter([0, 2, 4, 6, 8]));
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![8, 6, 4, 2, 0]
+        );
+    }
+
+    #[test]
+    fn retain_nothing_test() {
+        // Arrange
+        let mut list = (0..10).collect::<List<_>>();
+
+        // Act
+        list.retain(|_| false);
+
+        // Assert
+        assert!(list.is_empty());
+        assert_eq!(list.first(), None);
+        assert_eq!(list.last(), None);
+        assert_eq!(list.iter().count(), 0);
+    }
+
+    #[test]
+    fn retain_everything_test() {
+        // Arrange
+        let mut list = (0..10).collect::<List<_>>();
+
+        // Act
+        list.retain(|_| true);
+
+        // Assert
+        assert_eq!(list, (0..10).collect::<List<_>>());
+        assert_eq!(list.len(), 10);
+    }
+
+    #[test]
+    fn retain_ends_test() {
+        // Arrange
+        let mut list = (0..10).collect::<List<_>>();
+
+        // Act
+        list.retain(|v| *v != 0 && *v != 9);
+
+        // Assert
+        assert_eq!(list.first(), Some(&1));
+        assert_eq!(list.last(), Some(&8));
+        assert_eq!(list, (1..9).collect::<List<_>>());
+    }
+
+    #[test]
+    #[should_panic(expected = "list length overflow")]
+    fn push_back_len_overflow_test() {
+        // Arrange
+        let mut list = List::<i32>::new();
+        list.len = usize::MAX;
+
+        // Act
+        list.push_back(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "list length overflow")]
+    fn push_front_len_overflow_test() {
+        // Arrange
+        let mut list = List::<i32>::new();
+        list.len = usize::MAX;
+
+        // Act
+        list.push_front(0);
+    }
+
+    #[test]
+    fn insert_len_overflow_test() {
+        // Arrange
+        let mut list = (0..2).collect::<List<_>>();
+        list.len = usize::MAX;
+
+        // Act
+        let result = list.insert(1, 5);
+        list.len = 2;
+
+        // Assert
+        assert!(matches!(result, Err(Errors::LengthOverflow)));
+        assert_eq!(list, (0..2).collect::<List<_>>());
+    }
+
+    #[test]
+    fn split_off_test() {
+        // Arrange
+        let mut list = (0..6).collect::<List<_>>();
+
+        // Act
+        let other = list.split_off(4).unwrap();
+
+        // Assert
+        assert_eq!(list, (0..4).collect::<List<_>>());
+        assert_eq!(other, (4..6).collect::<List<_>>());
+        assert_eq!(list.last(), Some(&3));
+        assert_eq!(other.first(), Some(&4));
+        assert_eq!(list.iter().rev().count(), 4);
+        assert_eq!(other.iter().rev().count(), 2);
+    }
+
+    #[test]
+    fn split_off_ends_test() {
+        // Arrange
+        let mut list = (0..3).collect::<List<_>>();
+
+        // Act
+        let empty = list.split_off(3).unwrap();
+        let error = list.split_off(4);
+        let all = list.split_off(0).unwrap();
+
+        // Assert
+        assert!(empty.is_empty());
+        assert!(matches!(error, Err(Errors::OutOfBounds)));
+        assert!(list.is_empty());
+        assert_eq!(list.first(), None);
+        assert_eq!(all, (0..3).collect::<List<_>>());
+    }
+
+    #[test]
+    fn iter_mut_indexed_test() {
+        // Arrange
+        let mut list = List::from_iter([10, 10, 10]);
+
+        // Act
+        for (index, value) in list.iter_mut_indexed() {
+            *value += index;
+        }
+
+        // Assert
+        assert_eq!(list, List::from_iter([10, 11, 12]));
+    }
+
+    #[test]
+    fn append_test() {
+        // Arrange
+        let mut list = (0..3).collect::<List<_>>();
+        let mut other = (3..5).collect::<List<_>>();
+
+        // Act
+        list.append(&mut other);
+
+        // Assert
+        assert_eq!(list, (0..5).collect::<List<_>>());
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.iter().rev().count(), 5);
+        assert!(other.is_empty());
+        assert_eq!(other.len(), 0);
+        assert_eq!(other.iter().count(), 0);
+
+        // Other is still usable
+        other.push_back(7);
+        list.append(&mut other);
+        assert_eq!(list.last(), Some(&7));
+        assert!(other.is_empty());
+    }
+
+    #[test]
+    fn append_to_empty_test() {
+        // Arrange
+        let mut list = List::new();
+        let mut other = (0..3).collect::<List<_>>();
+
+        // Act
+        list.append(&mut other);
+
+        // Assert
+        assert_eq!(list, (0..3).collect::<List<_>>());
+        assert!(other.is_empty());
+        assert_eq!(other.first(), None);
+    }
+
+    #[test]
+    fn apply_permutation_test() {
+        // Arrange
+        let mut list = List::from_iter(['a', 'b', 'c', 'd']);
+
+        // Act
+        let reversed = list.apply_permutation(&[3, 2, 1, 0]);
+        let shuffled = list.apply_permutation(&[1, 3, 0, 2]);
+
+        // Assert
+        assert!(reversed.is_ok() && shuffled.is_ok());
+        assert_eq!(list, List::from_iter(['c', 'a', 'd', 'b']));
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec!['b', 'd', 'a', 'c']
+        );
+        assert_eq!(list.first(), Some(&'c'));
+        assert_eq!(list.last(), Some(&'b'));
+    }
+
+    #[test]
+    fn apply_permutation_invalid_test() {
+        // Arrange
+        let mut list = (0..3).collect::<List<_>>();
+
+        // Assert
+        assert!(matches!(
+            list.apply_permutation(&[0, 1]),
+            Err(Errors::InvalidPermutation)
+        ));
+        assert!(matches!(
+            list.apply_permutation(&[0, 1, 1]),
+            Err(Errors::InvalidPermutation)
+        ));
+        assert!(matches!(
+            list.apply_permutation(&[0, 1, 3]),
+            Err(Errors::InvalidPermutation)
+        ));
+        assert_eq!(list, (0..3).collect::<List<_>>());
+    }
+
+    #[test]
+    fn checked_swap_remove_test() {
+        // Arrange
+        let mut list = (0..5).collect::<List<_>>();
+
+        // Act
+        let removed = list.checked_swap_remove(1);
+        let removed_last = list.checked_swap_remove(3);
+
+        // Assert
+        assert!(matches!(removed, Ok(1)));
+        assert!(matches!(removed_last, Ok(3)));
+        assert_eq!(list, List::from_iter([0, 4, 2]));
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    fn checked_swap_remove_out_of_bounds_test() {
+        // Arrange
+        let mut list = (0..2).collect::<List<_>>();
+        let mut empty = List::<i32>::new();
+
+        // Assert
+        assert!(matches!(
+            list.checked_swap_remove(2),
+            Err(Errors::OutOfBounds)
+        ));
+        assert!(matches!(
+            empty.checked_swap_remove(0),
+            Err(Errors::OutOfBounds)
+        ));
+        assert_eq!(list, (0..2).collect::<List<_>>());
+    }
+
+    #[test]
+    fn rotate_until_front_test() {
+        // Arrange
+        let mut list = List::from_iter([1, 2, 3, 4]);
+
+        // Act
+        let found = list.rotate_until_front(|v| v % 2 == 0);
+
+        // Assert
+        assert!(found);
+        assert_eq!(list, List::from_iter([2, 3, 4, 1]));
+        assert_eq!(list.first(), Some(&2));
+        assert_eq!(list.last(), Some(&1));
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![1, 4, 3, 2]
+        );
+    }
+
+    #[test]
+    fn rotate_until_front_no_match_test() {
+        // Arrange
+        let mut list = List::from_iter([1, 2, 3, 4]);
+        let mut empty = List::<i32>::new();
+
+        // Assert
+        assert!(!list.rotate_until_front(|v| *v > 4));
+        assert_eq!(list, List::from_iter([1, 2, 3, 4]));
+        assert!(list.rotate_until_front(|v| *v == 1));
+        assert_eq!(list, List::from_iter([1, 2, 3, 4]));
+        assert!(!empty.rotate_until_front(|_| true));
+    }
+
+    #[test]
+    fn drain_middle_test() {
+        // Arrange
+        let mut list = (0..9).collect::<List<_>>();
+
+        // Act
+        let drained = list.drain(3..6).collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(drained, vec![3, 4, 5]);
+        assert_eq!(list, List::from_iter([0, 1, 2, 6, 7, 8]));
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![8, 7, 6, 2, 1, 0]
+        );
+        assert_eq!(list.len(), 6);
+    }
+
+    #[test]
+    fn drain_everything_test() {
+        // Arrange
+        let mut list = (0..9).collect::<List<_>>();
+
+        // Act
+        let drained = list.drain(..).collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(drained, (0..9).collect::<Vec<_>>());
+        assert!(list.is_empty());
+        assert_eq!(list.first(), None);
+        assert_eq!(list.last(), None);
+    }
+
+    #[test]
+    fn drain_open_ranges_test() {
+        // Arrange
+        let mut list = (0..9).collect::<List<_>>();
+
+        // Act
+        let tail = list.drain(7..).collect::<Vec<_>>();
+        let head = list.drain(..2).collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(tail, vec![7, 8]);
+        assert_eq!(head, vec![0, 1]);
+        assert_eq!(list, (2..7).collect::<List<_>>());
+        assert_eq!(list.drain(5..).count(), 0);
+    }
+
+    #[test]
+    fn drain_partial_drop_test() {
+        // Arrange
+        let mut list = (0..9).collect::<List<_>>();
+
+        // Act
+        let mut drain = list.drain(2..7);
+        assert_eq!(drain.next(), Some(2));
+        assert_eq!(drain.size_hint(), (4, Some(4)));
+        drop(drain);
+
+        // Assert
+        assert_eq!(list, List::from_iter([0, 1, 7, 8]));
+        assert_eq!(list.len(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn drain_out_of_bounds_test() {
+        // Arrange
+        let mut list = (0..3).collect::<List<_>>();
+
+        // Act
+        list.drain(1..4);
+    }
+
+    #[test]
+    fn send_sync_test() {
+        fn assert_send<S: Send>() {}
+        fn assert_sync<S: Sync>() {}
+
+        // Assert
+        assert_send::<List<i32>>();
+        assert_sync::<List<i32>>();
+        assert_send::<ListIter<'_, i32>>();
+        assert_sync::<ListIterMut<'_, i32>>();
+        assert_send::<super::super::list_into_iter::IntoIter<i32>>();
+    }
+
+    #[test]
+    fn send_to_thread_test() {
+        // Arrange
+        let mut list = (0..100).rev().collect::<List<_>>();
+
+        // Act
+        let handle = std::thread::spawn(move || {
+            list.sort();
+            list
+        });
+        let list = handle.join().unwrap();
+
+        // Assert
+        assert_eq!(list, (0..100).collect::<List<_>>());
+    }
+
+    #[test]
+    fn zip_with_test() {
+        // Arrange
+        let list = List::from_iter([1, 2, 3]);
+        let other = List::from_iter([10, 20]);
+
+        // Act
+        let sums = list.zip_with(&other, |a, b| a + b);
+        let labels = other.zip_with(&list, |a, b| format!("{a}:{b}"));
+
+        // Assert
+        assert_eq!(sums, List::from_iter([11, 22]));
+        assert_eq!(labels, List::from_iter(["10:1", "20:2"].map(String::from)));
+        assert!(list.zip_with(&List::<i32>::new(), |a, b| a + b).is_empty());
+    }
+
+    #[test]
+    fn get_pair_mut_test() {
+        // Arrange
+        let mut list = (0..5).collect::<List<_>>();
+
+        // Act
+        if let Some((a, b)) = list.get_pair_mut(4, 1) {
+            if a > b {
+                std::mem::swap(a, b);
+            }
+        }
+
+        // Assert
+        assert_eq!(list, List::from_iter([0, 4, 2, 3, 1]));
+    }
+
+    #[test]
+    fn get_pair_mut_invalid_test() {
+        // Arrange
+        let mut list = (0..5).collect::<List<_>>();
+
+        // Assert
+        assert!(list.get_pair_mut(2, 2).is_none());
+        assert!(list.get_pair_mut(0, 5).is_none());
+        assert!(list.get_pair_mut(5, 0).is_none());
+    }
+
+    #[test]
+    fn aggregate_chunks_test() {
+        // Arrange
+        let list = (1..6).collect::<List<_>>();
+
+        // Act
+        let sums = list.aggregate_chunks(2, |chunk| chunk.iter().copied().sum::<i32>());
+        let sizes = list.aggregate_chunks(10, |chunk| chunk.len());
+
+        // Assert
+        assert_eq!(sums, List::from_iter([3, 7, 5]));
+        assert_eq!(sizes, List::from_iter([5]));
+        assert!(List::<i32>::new()
+            .aggregate_chunks(2, |c| c.len())
+            .is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn aggregate_chunks_zero_size_test() {
+        // Arrange
+        let list = (1..6).collect::<List<_>>();
+
+        // Act
+        list.aggregate_chunks(0, |chunk| chunk.len());
+    }
+
+    #[test]
+    fn swap_test() {
+        // Arrange
+        let mut list = (0..5).collect::<List<_>>();
+
+        // Act
+        let result = list.swap(0, 3);
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(list, List::from_iter([3, 1, 2, 0, 4]));
+    }
+
+    #[test]
+    fn swap_same_index_test() {
+        // Arrange
+        let mut list = (0..5).collect::<List<_>>();
+
+        // Act
+        let result = list.swap(2, 2);
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(list, (0..5).collect::<List<_>>());
+    }
+
+    #[test]
+    fn swap_out_of_bounds_test() {
+        // Arrange
+        let mut list = (0..5).collect::<List<_>>();
+
+        // Assert
+        assert!(matches!(list.swap(1, 5), Err(Errors::OutOfBounds)));
+        assert!(matches!(list.swap(7, 7), Err(Errors::OutOfBounds)));
+        assert_eq!(list, (0..5).collect::<List<_>>());
+    }
+
+    #[test]
+    fn swap_with_test() {
+        // Arrange
+        let mut front = (0..3).collect::<List<_>>();
+        let mut back = (10..15).collect::<List<_>>();
+
+        // Act
+        front.swap_with(&mut back);
+
+        // Assert
+        assert_eq!(front, (10..15).collect::<List<_>>());
+        assert_eq!(back, (0..3).collect::<List<_>>());
+        assert_eq!(front.len(), 5);
+        assert_eq!(back.len(), 3);
+    }
+
+    #[test]
+    fn extend_from_refs_test() {
+        // Arrange
+        let mut list = List::from_iter([0]);
+        let source = [1, 2, 3];
+
+        // Act
+        list.extend(source.iter());
+
+        // Assert
+        assert_eq!(list.len(), 4);
+        assert_eq!(list, (0..4).collect::<List<_>>());
+        assert_eq!(source, [1, 2, 3]);
+    }
+
+    #[test]
+    fn from_vec_test() {
+        // Arrange
+        let vec = vec![3, 1, 2];
+
+        // Act
+        let list = List::from(vec.clone());
+
+        // Assert
+        assert_eq!(list.len(), 3);
+        assert_eq!(list, List::from_iter([3, 1, 2]));
+        assert_eq!(Vec::from(list), vec);
+    }
+
+    #[test]
+    fn vec_round_trip_test() {
+        // Arrange
+        let strings = vec!["a".to_string(), "b".to_string()];
+        let empty: Vec<i32> = Vec::new();
+
+        // Assert
+        assert_eq!(Vec::from(List::from(strings.clone())), strings);
+        assert_eq!(Vec::from(List::from(empty.clone())), empty);
+    }
+
+    #[test]
+    fn first_duplicate_test() {
+        // Arrange
+        let list = List::from_iter([1, 2, 3, 2, 1]);
+
+        // Assert
+        assert_eq!(list.first_duplicate(), Some((3, &2)));
+        assert_eq!((0..5).collect::<List<_>>().first_duplicate(), None);
+        assert_eq!(List::<i32>::new().first_duplicate(), None);
+    }
+
+    #[test]
+    fn from_array_test() {
+        // Arrange
+        let list = List::from([1, 2, 3]);
+        let single = List::from(["only"]);
+
+        // Assert
+        assert_eq!(list.len(), 3);
+        assert_eq!(list, (1..4).collect::<List<_>>());
+        assert_eq!(single.len(), 1);
+        assert_eq!(single.first(), Some(&"only"));
+    }
+
+    #[test]
+    fn from_empty_array_test() {
+        // Arrange
+        let list = List::<i32>::from([]);
+
+        // Assert
+        assert!(list.is_empty());
+        assert_eq!(list.first(), None);
+    }
+
+    #[test]
+    fn retain_first_n_test() {
+        // Arrange
+        let mut list = (1..7).collect::<List<_>>();
+
+        // Act
+        list.retain_first_n(2, |v| v % 2 == 0);
+
+        // Assert
+        assert_eq!(list, (1..6).collect::<List<_>>());
+        assert_eq!(list.last(), Some(&5));
+    }
+
+    #[test]
+    fn retain_first_n_zero_test() {
+        // Arrange
+        let mut list = (1..7).collect::<List<_>>();
+
+        // Act
+        list.retain_first_n(0, |v| v % 2 == 0);
+
+        // Assert
+        assert_eq!(list, List::from([1, 3, 5]));
+    }
+
+    #[test]
+    fn collect_nodes_test() {
+        // Arrange
+        let list = List::from(['a', 'b', 'c']);
+
+        // Act
+        let nodes = list.collect_nodes();
+
+        // Assert
+        assert_eq!(
+            nodes,
+            vec![
+                (None, 0, Some(1)),
+                (Some(0), 1, Some(2)),
+                (Some(1), 2, None)
+            ]
+        );
+        assert!(List::<i32>::new().collect_nodes().is_empty());
+    }
+
+    #[test]
+    fn merge_k_sorted_by_test() {
+        // Arrange
+        let lists = vec![List::from([1, 4]), List::from([2, 5]), List::from([3, 6])];
+
+        // Act
+        let merged = List::merge_k_sorted_by(lists, |a, b| a.cmp(b));
+
+        // Assert
+        assert_eq!(merged, (1..7).collect::<List<_>>());
+        assert_eq!(merged.len(), 6);
+        assert_eq!(
+            merged.iter().rev().copied().collect::<Vec<_>>(),
+            vec![6, 5, 4, 3, 2, 1]
+        );
+    }
+
+    #[test]
+    fn merge_k_sorted_by_stable_test() {
+        // Arrange
+        let lists = vec![
+            List::from([(1, 'a'), (3, 'a')]),
+            List::new(),
+            List::from([(1, 'b'), (2, 'b')]),
+            List::from([(3, 'c')]),
+        ];
+
+        // Act
+        let merged = List::merge_k_sorted_by(lists, |a, b| a.0.cmp(&b.0));
+
+        // Assert
+        assert_eq!(
+            merged,
+            List::from([(1, 'a'), (1, 'b'), (2, 'b'), (3, 'a'), (3, 'c')])
+        );
+        assert!(List::merge_k_sorted_by(Vec::<List<i32>>::new(), |a, b| a.cmp(b)).is_empty());
+    }
+
+    #[test]
+    fn iter_exact_size_test() {
+        // Arrange
+        let list = (0..5).collect::<List<_>>();
+        let mut iter = list.iter();
+
+        // Assert
+        assert_eq!(iter.len(), list.len());
+        iter.next();
+        assert_eq!(iter.len(), 4);
+        iter.next_back();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        iter.by_ref().for_each(drop);
+        assert_eq!(iter.len(), 0);
+        assert_eq!(List::<i32>::new().iter().len(), 0);
+    }
+
+    #[test]
+    fn iter_mut_exact_size_test() {
+        // Arrange
+        let mut list = (0..5).collect::<List<_>>();
+        let len = list.len();
+        let mut iter = list.iter_mut();
+
+        // Assert
+        assert_eq!(iter.len(), len);
+        iter.next_back();
+        iter.next_back();
+        assert_eq!(iter.len(), 3);
+        iter.next();
+        assert_eq!(iter.into_iter_ref().len(), 2);
+    }
+
+    #[test]
+    fn iter_while_test() {
+        // Arrange
+        let list = List::from([1, 2, 3, 0, 4]);
+
+        // Act
+        let taken = list.iter_while(|x| *x != 0).copied().collect::<Vec<_>>();
+        let until = list.iter_until(|x| *x >= 3).copied().collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(taken, vec![1, 2, 3]);
+        assert_eq!(until, vec![1, 2]);
+        assert_eq!(list.iter_while(|_| false).count(), 0);
+        assert_eq!(list.iter_until(|_| false).count(), 5);
+    }
+
+    #[test]
+    fn drain_back_while_test() {
+        // Arrange
+        let mut list = List::from([1, 2, 0, 0]);
+
+        // Act
+        let drained = list.drain_back_while(|x| *x == 0).collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(drained, vec![0, 0]);
+        assert_eq!(list, List::from([1, 2]));
+        assert_eq!(list.last(), Some(&2));
+        assert_eq!(list.drain_back_while(|x| *x > 5).count(), 0);
+        assert_eq!(
+            list.drain_back_while(|_| true).collect::<Vec<_>>(),
+            vec![2, 1]
+        );
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn dedup_test() {
+        // Arrange
+        let mut list = List::from([1, 1, 2, 3, 3, 3]);
+        let mut empty = List::<i32>::new();
+
+        // Act
+        list.dedup();
+        empty.dedup();
+
+        // Assert
+        assert_eq!(list, List::from([1, 2, 3]));
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.last(), Some(&3));
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![3, 2, 1]
+        );
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn dedup_by_key_test() {
+        // Arrange
+        let mut list = List::from([(1, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (1, 'e')]);
+
+        // Act
+        list.dedup_by_key(|pair| pair.0);
+
+        // Assert
+        assert_eq!(list, List::from([(1, 'a'), (2, 'c'), (1, 'd')]));
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.last(), Some(&(1, 'd')));
+    }
+
+    #[test]
+    fn contains_all_any_test() {
+        // Arrange
+        let list = List::from([4, 1, 3, 2]);
+
+        // Assert
+        assert!(list.contains_all(&[2, 4, 1]));
+        assert!(list.contains_all(&[]));
+        assert!(!list.contains_all(&[1, 5]));
+        assert!(list.contains_any(&[7, 3]));
+        assert!(!list.contains_any(&[7, 8]));
+        assert!(!list.contains_any(&[]));
+        assert!(list.contains_all_hashed(&[3, 2, 2]));
+        assert!(!list.contains_all_hashed(&[0]));
+        assert!(!List::new().contains_all_hashed(&[1]));
+    }
+
+    #[test]
+    fn into_rev_iter_test() {
+        // Arrange
+        let list = List::from([1, 2, 3]);
+
+        // Act
+        let values = list.into_rev_iter().collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(values, vec![3, 2, 1]);
+        assert_eq!(List::<i32>::new().into_rev_iter().next(), None);
+    }
+
+    #[test]
+    fn rebuild_prev_links_test() {
+        // Arrange
+        let mut list = List::from([0, 1, 2, 3]);
+        let second = list
+            .start
+            .map(|ptr| unsafe { (*ptr).next.unwrap() })
+            .unwrap();
+        let last = list.end.unwrap();
+
+        // Act (desync the backward links, the end and the length)
+        unsafe {
+            (*second).previous = None;
+            (*last).previous = Some(last);
+        }
+        list.end = Some(second);
+        list.len = 7;
+        list.rebuild_prev_links();
+
+        // Assert
+        assert_eq!(
+            list.collect_nodes(),
+            vec![
+                (None, 0, Some(1)),
+                (Some(0), 1, Some(2)),
+                (Some(1), 2, Some(3)),
+                (Some(2), 3, None)
+            ]
+        );
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.last(), Some(&3));
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![3, 2, 1, 0]
+        );
+    }
+
+    #[test]
+    fn recycle_churn_test() {
+        // Arrange
+        let mut list = List::new();
+        list.set_recycle_limit(16);
+        let mut expected = std::collections::VecDeque::new();
+
+        // Act
+        for i in 0..10_000 {
+            list.push_back(i);
+            expected.push_back(i);
+            if i % 3 == 0 {
+                list.push_front(-i);
+                expected.push_front(-i);
+            }
+            if i % 2 == 0 {
+                assert_eq!(list.pop_front(), expected.pop_front());
+                assert_eq!(list.pop_back(), expected.pop_back());
+            }
+            assert!(list.recycled.len() <= 16);
+        }
+
+        // Assert
+        assert_eq!(list.len(), expected.len());
+        assert!(list.iter().eq(expected.iter()));
+        assert!(list.iter().rev().eq(expected.iter().rev()));
+    }
+
+    #[test]
+    fn recycle_limit_test() {
+        // Arrange
+        let mut list = (0..10).collect::<List<_>>();
+        list.set_recycle_limit(4);
+
+        // Act
+        list.clear();
+        let recycled = list.recycled.len();
+        list.push_back(1);
+        let after_push = list.recycled.len();
+        list.set_recycle_limit(1);
+
+        // Assert
+        assert_eq!(recycled, 4);
+        assert_eq!(after_push, 3);
+        assert_eq!(list.recycled.len(), 1);
+        assert_eq!(list, List::from([1]));
+    }
+
+    #[test]
+    fn recycle_no_leak_test() {
+        // Arrange
+        let value = std::rc::Rc::new(0);
+        let mut list = (0..8)
+            .map(|_| std::rc::Rc::clone(&value))
+            .collect::<List<_>>();
+        list.set_recycle_limit(8);
+
+        // Act (recycled nodes must not keep or drop their old value)
+        list.pop_front();
+        list.pop_back();
+        list.checked_swap_remove(2).unwrap();
+        assert_eq!(std::rc::Rc::strong_count(&value), 6);
+        list.push_back(std::rc::Rc::clone(&value));
+        drop(list);
+
+        // Assert
+        assert_eq!(std::rc::Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn iter_circular_from_test() {
+        // Arrange
+        let list = List::from([0, 1, 2, 3]);
+
+        // Act
+        let from_two = list.iter_circular_from(2).copied().collect::<Vec<_>>();
+        let wrapped = list.iter_circular_from(5).copied().collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(from_two, vec![2, 3, 0, 1]);
+        assert_eq!(wrapped, vec![1, 2, 3, 0]);
+        assert_eq!(list.iter_circular_from(0).count(), 4);
+        assert_eq!(List::<i32>::new().iter_circular_from(3).next(), None);
+    }
+
+    #[test]
+    fn dedup_by_key_keeping_test() {
+        // Arrange
+        let mut list = List::from([("t1", 5), ("t1", 9), ("t2", 3)]);
+        let mut ties = List::from([(1, 2, 'a'), (1, 2, 'b'), (2, 0, 'c'), (2, 1, 'd')]);
+
+        // Act
+        list.dedup_by_key_keeping(|tick| tick.0, |tick| tick.1);
+        ties.dedup_by_key_keeping(|item| item.0, |item| item.1);
+
+        // Assert
+        assert_eq!(list, List::from([("t1", 9), ("t2", 3)]));
+        assert_eq!(list.first(), Some(&("t1", 9)));
+        assert_eq!(ties, List::from([(1, 2, 'a'), (2, 1, 'd')]));
+        assert_eq!(ties.len(), 2);
+        assert_eq!(ties.last(), Some(&(2, 1, 'd')));
+    }
+
+    #[test]
+    fn first_last_mut_test() {
+        // Arrange
+        let mut list = List::from([1, 2, 3]);
+        let mut empty = List::<i32>::new();
+
+        // Act
+        *list.first_mut().unwrap() += 10;
+        *list.last_mut().unwrap() *= 5;
+
+        // Assert
+        assert_eq!(list.first(), Some(&11));
+        assert_eq!(list.last(), Some(&15));
+        assert_eq!(list, List::from([11, 2, 15]));
+        assert_eq!(empty.first_mut(), None);
+        assert_eq!(empty.last_mut(), None);
+    }
+
+    #[test]
+    fn sort_keeps_len_test() {
+        for size in [0, 1, 2, 3, 1000] {
+            // Arrange
+            let mut list = (0..size).map(|i| (i * 7919) % 1009).collect::<List<_>>();
+
+            // Act
+            list.sort_by(|a, b| a.cmp(b));
+
+            // Assert
+            assert_eq!(list.iter().count(), list.len());
+            assert_eq!(list.iter().rev().count(), size);
+            assert_eq!(list.collect_nodes().len(), size);
+            assert!(list.iter().zip(list.iter().skip(1)).all(|(a, b)| a <= b));
+        }
+    }
+
+    #[test]
+    fn iter_clone_peek_ahead_test() {
+        // Arrange
+        let list = List::from(['a', 'b', 'c', 'd']);
+        let mut iter = list.iter();
+        iter.next();
+
+        // Act (look ahead on a clone, then keep going with the original)
+        let saved = Clone::clone(&iter);
+        let ahead = Clone::clone(&saved).nth(2);
+
+        // Assert
+        assert_eq!(ahead, Some(&'d'));
+        assert_eq!(saved.len(), 3);
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some(&'b'));
+        assert_eq!(saved.copied().collect::<String>(), "bcd");
+    }
+
+    #[test]
+    fn min_max_test() {
+        // Arrange
+        let list = List::from([4, -2, 9, 0, 9, -2, 3]);
+        let even = List::from([5, 1, 8, 3]);
+        let single = List::from([7]);
+
+        // Act
+        let (min, max) = list.min_max().unwrap();
+
+        // Assert
+        assert_eq!((*min, *max), (-2, 9));
+        assert!(std::ptr::eq(min, list.iter().nth(1).unwrap()));
+        assert!(std::ptr::eq(max, list.iter().nth(4).unwrap()));
+        assert_eq!(even.min_max(), Some((&1, &8)));
+        assert_eq!(single.min_max(), Some((&7, &7)));
+        assert_eq!(List::<i32>::new().min_max(), None);
+    }
+
+    #[test]
+    fn rotate_left_right_test() {
+        // Arrange
+        let mut left = (0..5).collect::<List<_>>();
+        let mut right = (0..5).collect::<List<_>>();
+        let mut wrapped = (0..5).collect::<List<_>>();
+        let mut empty = List::<i32>::new();
+
+        // Act
+        left.rotate_left(2);
+        right.rotate_right(2);
+        wrapped.rotate_left(7);
+        empty.rotate_left(3);
+        empty.rotate_right(3);
+
+        // Assert
+        assert_eq!(left, List::from([2, 3, 4, 0, 1]));
+        assert_eq!(right, List::from([3, 4, 0, 1, 2]));
+        assert_eq!(wrapped, left);
+        assert_eq!(
+            left.iter().rev().copied().collect::<Vec<_>>(),
+            vec![1, 0, 4, 3, 2]
+        );
+        assert_eq!(right.last(), Some(&2));
+        assert!(empty.is_empty());
+
+        // A full cycle and its inverse leave the list unchanged
+        right.rotate_right(5);
+        right.rotate_left(2);
+        assert_eq!(right, List::from([0, 1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn retain_unique_by_key_test() {
+        // Arrange
+        let mut records = List::from([(1, "a"), (2, "b"), (1, "c"), (3, "d"), (2, "e")]);
+
+        // Act
+        records.retain_unique_by_key(|record| record.0);
+
+        // Assert
+        assert_eq!(records, List::from([(1, "a"), (2, "b"), (3, "d")]));
+        assert_eq!(records.len(), 3);
+        assert_eq!(records.last(), Some(&(3, "d")));
+    }
+
+    #[test]
+    fn truncate_test() {
+        // Arrange
+        let mut list = (0..5).collect::<List<_>>();
+        let mut cleared = (0..5).collect::<List<_>>();
+        let mut longer = (0..5).collect::<List<_>>();
+
+        // Act
+        list.truncate(2);
+        cleared.truncate(0);
+        longer.truncate(9);
+
+        // Assert
+        assert_eq!(list, List::from([0, 1]));
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.last(), Some(&1));
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![1, 0]);
+        assert!(cleared.is_empty());
+        assert_eq!(cleared.first(), None);
+        assert_eq!(longer, (0..5).collect::<List<_>>());
+
+        // The list stays usable after truncating
+        list.push_back(7);
+        assert_eq!(list, List::from([0, 1, 7]));
+    }
+
+    #[test]
+    fn truncate_drops_tail_test() {
+        // Arrange
+        let value = std::rc::Rc::new(());
+        let mut list = (0..6)
+            .map(|_| std::rc::Rc::clone(&value))
+            .collect::<List<_>>();
+
+        // Act
+        list.truncate(4);
+
+        // Assert
+        assert_eq!(std::rc::Rc::strong_count(&value), 5);
+    }
+
+    #[test]
+    fn trim_front_to_test() {
+        // Arrange
+        let mut list = List::from([1, 2, 3, 4, 5]);
+
+        // Act
+        let evicted = list.trim_front_to(3);
+        let nothing = list.trim_front_to(10);
+
+        // Assert
+        assert_eq!(evicted, vec![1, 2]);
+        assert!(nothing.is_empty());
+        assert_eq!(list, List::from([3, 4, 5]));
+        assert_eq!(list.first(), Some(&3));
+        assert_eq!(list.trim_front_to(0), vec![3, 4, 5]);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn insert_sorted_test() {
+        // Arrange
+        let mut list = List::from([1, 3, 5, 7]);
+        let mut empty = List::new();
+
+        // Act
+        let middle = list.insert_sorted(4);
+        let front = list.insert_sorted(0);
+        let back = list.insert_sorted(9);
+        let duplicate = list.insert_sorted(3);
+        let sole = empty.insert_sorted(2);
+
+        // Assert
+        assert_eq!((middle, front, back, duplicate), (2, 0, 6, 2));
+        assert_eq!(list, List::from([0, 1, 3, 3, 4, 5, 7, 9]));
+        assert_eq!(list.last(), Some(&9));
+        assert_eq!(list.first(), Some(&0));
+        assert_eq!(sole, 0);
+        assert_eq!(empty, List::from([2]));
+    }
+
+    #[test]
+    fn binary_search_test() {
+        // Arrange
+        let list = List::from([1, 3, 5, 7, 9, 11]);
+        let values = [1, 3, 5, 7, 9, 11];
+
+        // Assert (every target, present or not, matches the slice contract)
+        for target in 0..13 {
+            assert_eq!(list.binary_search(&target), values.binary_search(&target));
+        }
+        assert_eq!(list.binary_search(&7), Ok(3));
+        assert_eq!(list.binary_search(&4), Err(2));
+        assert_eq!(list.binary_search(&12), Err(6));
+        assert_eq!(List::new().binary_search(&1), Err(0));
+        assert_eq!(List::from([2]).binary_search(&3), Err(1));
+        assert!(matches!(List::from([2, 2, 2]).binary_search(&2), Ok(0..=2)));
+    }
+
+    #[test]
+    fn split_at_mut_test() {
+        // Arrange
+        let mut list = (0..6).collect::<List<_>>();
+
+        // Act
+        let (first, second) = list.split_at_mut(2).unwrap();
+        assert_eq!((first.len(), second.len()), (2, 4));
+        std::thread::scope(|scope| {
+            scope.spawn(move || first.for_each(|value| *value += 100));
+            scope.spawn(move || second.rev().for_each(|value| *value *= -1));
+        });
+
+        // Assert
+        assert_eq!(list, List::from([100, 101, -2, -3, -4, -5]));
+        assert!(list.split_at_mut(7).is_none());
     }
-}
 
-impl<T> Index<usize> for List<T> {
-    type Output = T;
+    #[test]
+    fn split_at_mut_bounds_test() {
+        // Arrange
+        let mut list = (0..3).collect::<List<_>>();
 
-    fn index(&self, index: usize) -> &Self::Output {
-        self.get(index).expect("Index out of bounds")
+        // Act
+        let (mut first, mut second) = list.split_at_mut(1).unwrap();
+
+        // Assert (each half stops at the split from both directions)
+        assert_eq!(first.next_back(), Some(&mut 0));
+        assert_eq!(first.next(), None);
+        assert_eq!(second.next_back(), Some(&mut 2));
+        assert_eq!(second.next_back(), Some(&mut 1));
+        assert_eq!(second.next_back(), None);
+
+        // Empty halves at both ends
+        let (empty, all) = list.split_at_mut(0).unwrap();
+        assert_eq!((empty.count(), all.count()), (0, 3));
+        let (all, empty) = list.split_at_mut(3).unwrap();
+        assert_eq!((all.count(), empty.count()), (3, 0));
+        let mut empty_list = List::<i32>::new();
+        let (left, right) = empty_list.split_at_mut(0).unwrap();
+        assert_eq!((left.count(), right.count()), (0, 0));
     }
-}
 
-impl<T> IndexMut<usize> for List<T> {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        self.get_mut(index).expect("Index out of bounds")
+    #[test]
+    fn chunks_test() {
+        // Arrange
+        let list = (0..7).collect::<List<_>>();
+
+        // Act
+        let chunks = list.chunks(3).collect::<Vec<_>>();
+        let whole = list.chunks(10).collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(chunks, vec![vec![&0, &1, &2], vec![&3, &4, &5], vec![&6]]);
+        assert_eq!(whole, vec![list.iter().collect::<Vec<_>>()]);
+        assert_eq!(list.chunks(3).len(), 3);
+        assert_eq!(List::<i32>::new().chunks(2).next(), None);
     }
-}
 
-impl<T: Debug> Debug for List<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_list().entries(self.iter()).finish()
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn chunks_zero_size_test() {
+        // Arrange
+        let list = (0..3).collect::<List<_>>();
+
+        // Act
+        list.chunks(0);
     }
-}
 
-impl<T> Extend<T> for List<T> {
-    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
-        for item in iter {
-            self.push_back(item);
-        }
+    #[test]
+    fn windows_test() {
+        // Arrange
+        let list = (0..4).collect::<List<_>>();
+
+        // Act
+        let pairs = list.windows(2).collect::<Vec<_>>();
+        let deltas = list.windows(2).map(|w| w[1] - w[0]).collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(pairs, vec![vec![&0, &1], vec![&1, &2], vec![&2, &3]]);
+        assert_eq!(deltas, vec![1, 1, 1]);
+        assert_eq!(list.windows(4).count(), 1);
+        assert_eq!(list.windows(5).next(), None);
     }
-}
 
-impl<T: PartialEq> PartialEq for List<T> {
-    fn eq(&self, other: &Self) -> bool {
-        if self.len() != other.len() {
-            return false;
-        }
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn windows_zero_size_test() {
+        // Arrange
+        let list = (0..3).collect::<List<_>>();
 
-        self.iter().zip(other.iter()).all(|(a, b)| a == b)
+        // Act
+        list.windows(0);
     }
-}
 
-impl<T: Eq> Eq for List<T> {}
+    #[test]
+    fn map_test() {
+        // Arrange
+        let list = (0..3).collect::<List<i32>>();
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        // Act
+        let strings = list.map(|value| value.to_string());
+        let owned = strings.map_into(|value| value + "!");
+
+        // Assert
+        assert_eq!(list, List::from([0, 1, 2]));
+        assert_eq!(owned, List::from(["0!", "1!", "2!"].map(String::from)));
+        assert_eq!(owned.last().map(String::as_str), Some("2!"));
+        assert!(List::<i32>::new().map(|v| v + 1).is_empty());
+    }
 
     #[test]
-    fn constructor_test() {
+    fn retain_mut_test() {
         // Arrange
-        let list = List::<i32>::new();
+        let mut list = List::from([3, 8, 1, 9, 5]);
+
+        // Act
+        list.retain_mut(|value| {
+            *value += 1;
+            *value <= 6
+        });
+
+        // Assert
+        assert_eq!(list, List::from([4, 2, 6]));
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.last(), Some(&6));
+    }
 
+    #[test]
+    fn find_test() {
         // Arrange
-        assert!(list.is_empty());
-        assert_eq!(list.iter().count(), 0);
+        let numbers = List::from([3, 7, 4, 10]);
+        let words = List::from(["one", "x2", "42", "7"]);
+
+        // Act
+        let even = numbers.find(|value| *value % 2 == 0);
+        let parsed = words.find_map(|word| word.parse::<i32>().ok());
+
+        // Assert
+        assert_eq!(even, Some(&4));
+        assert_eq!(parsed, Some(42));
+        assert_eq!(numbers.find(|value| **value > 10), None);
+        assert_eq!(words.find_map(|word| word.strip_prefix('y')), None);
     }
 
     #[test]
-    fn push_back_test() {
+    fn eq_vec_array_test() {
         // Arrange
-        let mut list = List::new();
+        let list = List::from([1, 2, 3]);
+
+        // Assert
+        assert_eq!(list, vec![1, 2, 3]);
+        assert_eq!(list, [1, 2, 3]);
+        assert_eq!(list, *[1, 2, 3].as_slice());
+        assert_eq!(vec![1, 2, 3], list);
+        assert_eq!([1, 2, 3], list);
+        assert_ne!(list, vec![1, 2]);
+        assert_ne!(list, vec![1, 2, 4]);
+        assert_ne!(list, [3, 2, 1]);
+        assert_ne!([1, 2, 3, 4], list);
+        assert_eq!(List::<i32>::new(), Vec::new());
+    }
+
+    #[test]
+    fn shrink_to_fit_test() {
+        // Arrange
+        let mut list = (0..10).collect::<List<_>>();
+        list.set_recycle_limit(8);
+        list.truncate(3);
+        assert_eq!(list.recycled.len(), 7);
 
         // Act
-        list.push_back(1);
-        list.push_back(2);
-        let mut iter = list.iter();
+        list.shrink_to_fit();
+
+        // Assert
+        assert!(list.recycled.is_empty());
+        assert_eq!(list, [0, 1, 2]);
+        assert_eq!(list.last(), Some(&2));
 
+        // The pool fills up again after shrinking
+        list.pop_back();
+        assert_eq!(list.recycled.len(), 1);
+        list.push_back(5);
+        assert_eq!(list, [0, 1, 5]);
+    }
+
+    #[test]
+    fn concatenate_bounds_test() {
         // Arrange
-        assert_eq!(list.len(), 2);
-        assert_eq!(list.iter().count(), 2);
-        assert_eq!(iter.next(), Some(&1));
-        assert_eq!(iter.next(), Some(&2));
-        assert_eq!(iter.next(), None);
+        let mut list = List::from([1, 2]);
+        let mut empty = List::new();
+
+        // Act
+        list.concatenate(List::from([3, 4]));
+        list.concatenate(List::new());
+        empty.concatenate(List::from([5]));
+        empty.concatenate(List::from([6, 7]));
+
+        // Assert
+        assert_eq!(list, [1, 2, 3, 4]);
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![4, 3, 2, 1]
+        );
+        assert_eq!(empty, [5, 6, 7]);
+        assert_eq!((empty.first(), empty.last()), (Some(&5), Some(&7)));
     }
 
     #[test]
-    fn push_front_test() {
+    fn to_ptr_vec_test() {
         // Arrange
-        let mut list = List::new();
+        let list = (0..5).collect::<List<_>>();
 
         // Act
-        list.push_front(1);
-        list.push_front(2);
-        let mut iter = list.iter();
+        let snapshot = list.to_ptr_vec();
+
+        // Assert
+        assert_eq!(snapshot.len(), 5);
+        assert_eq!(*snapshot[3], 3);
+        assert_eq!(*snapshot[0], 0);
+        assert!(std::ptr::eq(snapshot[4], list.last().unwrap()));
+        assert!(snapshot
+            .iter()
+            .zip(list.iter())
+            .all(|(a, b)| std::ptr::eq(*a, b)));
+    }
 
+    #[test]
+    fn sort_large_list_test() {
         // Arrange
-        assert_eq!(list.len(), 2);
-        assert_eq!(list.iter().count(), 2);
-        assert_eq!(iter.next(), Some(&2));
-        assert_eq!(iter.next(), Some(&1));
-        assert_eq!(iter.next(), None);
+        let size = 100_000;
+        let mut list = (0..size).map(|i| (i * 7919) % size).collect::<List<_>>();
+
+        // Act
+        list.sort();
+
+        // Assert
+        assert_eq!(list.len(), size);
+        assert!(list.iter().copied().eq(0..size));
+        assert!(list.iter().rev().copied().eq((0..size).rev()));
     }
 
     #[test]
-    fn pop_front_test() {
+    fn sort_by_stable_odd_len_test() {
         // Arrange
-        let mut list = List::new();
-        list.extend(0..5);
+        let mut list = List::from([(3, 'a'), (1, 'b'), (3, 'c'), (2, 'd'), (1, 'e')]);
+
+        // Act
+        list.sort_by(|a, b| a.0.cmp(&b.0));
+
+        // Assert
+        assert_eq!(list, [(1, 'b'), (1, 'e'), (2, 'd'), (3, 'a'), (3, 'c')]);
+        assert_eq!(list.last(), Some(&(3, 'c')));
+    }
 
+    #[test]
+    fn is_sorted_test() {
         // Arrange
-        assert_eq!(list.len(), 5);
-        assert_eq!(list.iter().count(), 5);
-        assert_eq!(list.pop_front(), Some(0));
-        assert_eq!(list.pop_front(), Some(1));
-        assert_eq!(list.pop_front(), Some(2));
-        assert_eq!(list.pop_front(), Some(3));
-        assert_eq!(list.pop_front(), Some(4));
+        let mut list = List::from([4, 1, 3, 1, 2]);
+        let shuffled = list.clone();
+
+        // Act
+        list.sort();
+
+        // Assert
+        assert!(list.is_sorted());
+        assert!(!shuffled.is_sorted());
+        assert!(List::<i32>::new().is_sorted());
+        assert!(List::from([7]).is_sorted());
+        assert!(List::from([5, 3, 3, 0]).is_sorted_by(|a, b| a >= b));
+        assert!(!list.is_sorted_by(|a, b| a < b));
     }
 
     #[test]
-    fn pop_back_test() {
+    fn min_max_element_test() {
         // Arrange
-        let mut list = List::new();
-        list.extend((0..5).rev());
+        let list = List::from([4, -1, 7, 7, -1]);
+        let pairs = List::from([('a', 3), ('b', 1), ('c', 5), ('d', 1)]);
 
+        // Assert
+        assert_eq!(list.min_element(), Some(&-1));
+        assert_eq!(list.max_element(), Some(&7));
+        assert!(std::ptr::eq(
+            list.min_element().unwrap(),
+            list.iter().nth(1).unwrap()
+        ));
+        assert!(std::ptr::eq(
+            list.max_element().unwrap(),
+            list.iter().nth(3).unwrap()
+        ));
+        assert_eq!(pairs.min_by(|a, b| a.1.cmp(&b.1)), Some(&('b', 1)));
+        assert_eq!(pairs.max_by(|a, b| a.1.cmp(&b.1)), Some(&('c', 5)));
+        assert_eq!(pairs.max_by(|a, b| b.1.cmp(&a.1)), Some(&('d', 1)));
+        assert_eq!(List::<i32>::new().min_element(), None);
+        assert_eq!(List::<i32>::new().max_by(|a, b| a.cmp(b)), None);
+    }
+
+    #[test]
+    fn fold_reduce_test() {
         // Arrange
-        assert_eq!(list.len(), 5);
-        assert_eq!(list.pop_back(), Some(0));
-        assert_eq!(list.pop_back(), Some(1));
-        assert_eq!(list.pop_back(), Some(2));
-        assert_eq!(list.pop_back(), Some(3));
-        assert_eq!(list.pop_back(), Some(4));
+        let list = (0..5).collect::<List<i32>>();
+        let words = List::from(["a", "b", "c"]);
+
+        // Act
+        let joined = words.fold(String::new(), |mut acc, word| {
+            acc.push_str(word);
+            acc
+        });
+        let largest = list.reduce(|a, b| *a.max(b));
+
+        // Assert
+        assert_eq!(list.sum::<i32>(), 10);
+        assert_eq!(list.product::<i32>(), 0);
+        assert_eq!(List::from([2, 3, 4]).product::<i32>(), 24);
+        assert_eq!(joined, "abc");
+        assert_eq!(largest, Some(4));
+        assert_eq!(List::from([9]).reduce(|a, b| a + b), Some(9));
+        assert_eq!(List::<i32>::new().reduce(|a, b| a + b), None);
+        assert_eq!(List::<i32>::new().sum::<i32>(), 0);
     }
 
     #[test]
-    fn insert_empty_test() {
+    fn from_iter_large_test() {
         // Arrange
-        let mut list = List::new();
-        let _ = list.insert(0, 1);
+        let mut pushed = List::new();
+        for i in 0..50_000 {
+            pushed.push_back(i);
+        }
+
+        // Act
+        let collected = (0..50_000).collect::<List<_>>();
 
         // Assert
-        assert_eq!(list[0], 1);
+        assert_eq!(collected, pushed);
+        assert_eq!(collected.len(), 50_000);
+        assert_eq!(collected.last(), Some(&49_999));
+        assert!(collected.iter().rev().eq(pushed.iter().rev()));
+        assert_eq!(
+            std::iter::empty::<i32>()
+                .collect::<List<_>>()
+                .collect_nodes(),
+            vec![]
+        );
+        assert_eq!(
+            std::iter::once(1).collect::<List<_>>().collect_nodes(),
+            vec![(None, 0, None)]
+        );
+    }
+
+    #[test]
+    fn detached_node_relocate_test() {
+        // Arrange
+        let mut list = List::from([1, 2, 3]);
+        let address = list.first().unwrap() as *const i32;
+
+        // Act
+        let mut node = list.take_first().unwrap();
+        *node.value_mut() += 10;
+        list.push_back_node(node);
+
+        // Assert (same allocation, now at the back)
+        assert_eq!(list, [2, 3, 11]);
+        assert_eq!(list.len(), 3);
+        assert!(std::ptr::eq(list.last().unwrap(), address));
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![11, 3, 2]
+        );
+    }
+
+    #[test]
+    fn detached_node_between_lists_test() {
+        // Arrange
+        let mut source = List::from([String::from("a"), String::from("b")]);
+        let mut target = List::new();
+
+        // Act
+        let last = source.take_last().unwrap();
+        assert_eq!(last.value(), "b");
+        target.push_front_node(last);
+        target.push_front_node(source.take_last().unwrap());
+
+        // Assert
+        assert!(source.is_empty());
+        assert_eq!(source.take_first().map(|node| node.into_value()), None);
+        assert_eq!(target, [String::from("a"), String::from("b")]);
+        assert_eq!(target.first().map(String::as_str), Some("a"));
+        assert_eq!(target.take_first().unwrap().into_value(), "a");
+        assert_eq!(target.len(), 1);
+    }
+
+    #[test]
+    fn group_by_test() {
+        // Arrange
+        let list = List::from([1, 1, 2, 2, 2, 3]);
+
+        // Act
+        let groups = list.group_by(|a, b| a == b).collect::<Vec<_>>();
+        let ascending = List::from([1, 2, 4, 3, 5, 0])
+            .group_by(|a, b| a < b)
+            .map(|group| group.len())
+            .collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(groups, vec![vec![&1, &1], vec![&2, &2, &2], vec![&3]]);
+        assert_eq!(ascending, vec![3, 2, 1]);
+        assert_eq!(List::<i32>::new().group_by(|a, b| a == b).next(), None);
+    }
+
+    #[test]
+    fn rchunks_test() {
+        // Arrange
+        let list = (0..7).collect::<List<_>>();
+
+        // Act
+        let chunks = list.rchunks(3).collect::<Vec<_>>();
+        let whole = list.rchunks(10).collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(chunks, vec![vec![&4, &5, &6], vec![&1, &2, &3], vec![&0]]);
+        assert_eq!(whole, vec![list.iter().collect::<Vec<_>>()]);
+        assert_eq!(list.rchunks(3).len(), 3);
+        assert_eq!(List::<i32>::new().rchunks(2).next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn rchunks_zero_size_test() {
+        // Arrange
+        let list = (0..3).collect::<List<_>>();
+
+        // Act
+        list.rchunks(0);
+    }
+
+    #[test]
+    fn add_test() {
+        // Arrange
+        let left = (0..3).collect::<List<_>>();
+        let right = (3..6).collect::<List<_>>();
+
+        // Act
+        let list = left + right;
+        let with_empty = list.clone() + List::new();
+
+        // Assert
+        assert_eq!(list, (0..6).collect::<List<_>>());
+        assert_eq!(list.last(), Some(&5));
+        assert_eq!(with_empty, list);
+        assert_eq!(List::new() + List::from([1]), [1]);
+    }
+
+    #[test]
+    fn add_assign_test() {
+        // Arrange
+        let mut list = List::from([1, 2]);
+        let mut shared = List::new();
+        let item = std::rc::Rc::new(3);
+
+        // Act
+        list += List::from([3, 4]);
+        list += List::new();
+        shared += List::from([std::rc::Rc::clone(&item)]);
+        shared += List::from([std::rc::Rc::clone(&item)]);
+
+        // Assert
+        assert_eq!(list, [1, 2, 3, 4]);
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![4, 3, 2, 1]
+        );
+        // The right-hand lists were moved in, their nodes now belong to `shared`
+        assert_eq!(shared.len(), 2);
+        assert_eq!(std::rc::Rc::strong_count(&item), 3);
+        drop(shared);
+        assert_eq!(std::rc::Rc::strong_count(&item), 1);
+    }
+
+    #[test]
+    fn repeat_test() {
+        // Arrange
+        let list = List::from([1, 2]);
+
+        // Act
+        let repeated = list.repeat(3);
+        let once = list.repeat(1);
+        let none = list.repeat(0);
+
+        // Assert
+        assert_eq!(repeated, [1, 2, 1, 2, 1, 2]);
+        assert_eq!(repeated.last(), Some(&2));
+        assert_eq!(once, list);
+        assert!(none.is_empty());
+        assert!(List::<i32>::new().repeat(4).is_empty());
+    }
+
+    #[test]
+    fn iter_indexed_test() {
+        // Arrange
+        let list = (10..15).collect::<List<_>>();
+
+        // Act
+        let mut iter = list.iter_indexed();
+        let first = iter.next();
+        let last = iter.next_back();
+        let second = iter.next();
+        let fourth = iter.next_back();
+        let remaining = iter.len();
+        let third = iter.next_back();
+
+        // Assert
+        assert_eq!(first, Some((0, &10)));
+        assert_eq!(last, Some((4, &14)));
+        assert_eq!(second, Some((1, &11)));
+        assert_eq!(fourth, Some((3, &13)));
+        assert_eq!(remaining, 1);
+        assert_eq!(third, Some((2, &12)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(
+            list.iter_indexed().rev().collect::<Vec<_>>(),
+            list.iter().enumerate().rev().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn clear_large_test() {
+        // Arrange
+        let item = std::rc::Rc::new(0);
+        let mut list = (0..100_000)
+            .map(|_| std::rc::Rc::clone(&item))
+            .collect::<List<_>>();
+
+        // Act
+        list.clear();
+
+        // Assert
+        assert!(list.is_empty());
+        assert_eq!((list.first(), list.last()), (None, None));
+        assert_eq!(std::rc::Rc::strong_count(&item), 1);
+
+        // The list is still usable after being cleared
+        list.push_back(std::rc::Rc::clone(&item));
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.iter().rev().count(), 1);
     }
 
     #[test]
-    fn insert_push_front_test() {
+    fn count_where_test() {
         // Arrange
-        let mut list = List::new();
-        list.push_front(1);
-        let _ = list.insert(0, 0);
+        let list = (0..10).collect::<List<_>>();
+        let empty = List::<i32>::new();
+
+        // Act
+        let evens = list.count_where(|x| x % 2 == 0);
+        let large = list.count_where(|x| *x > 100);
+        let in_empty = empty.count_where(|_| true);
 
         // Assert
-        assert_eq!(list.len(), 2);
-        assert_eq!(list.iter().count(), 2);
-        for (expected, real) in list.iter().zip(0..2) {
-            assert_eq!(expected, &real);
-        }
+        assert_eq!(evens, 5);
+        assert_eq!(large, 0);
+        assert_eq!(in_empty, 0);
     }
 
     #[test]
-    fn insert_push_back_test() {
+    fn swap_remove_test() {
         // Arrange
-        let mut list = List::new();
-        list.push_front(1);
-        let _ = list.insert(1, 2);
+        let mut list = (0..5).collect::<List<_>>();
+
+        // Act
+        let removed = list.swap_remove(1);
+        let removed_last = list.swap_remove(3);
+        let error = list.swap_remove(3);
 
         // Assert
-        assert_eq!(list.len(), 2);
-        assert_eq!(list.iter().count(), 2);
-        for (expected, real) in list.iter().zip(1..3) {
-            assert_eq!(expected, &real);
-        }
+        assert!(matches!(removed, Ok(1)));
+        assert!(matches!(removed_last, Ok(3)));
+        assert!(matches!(error, Err(Errors::OutOfBounds)));
+        // The former tail took the place of the removed element
+        assert_eq!(list, [0, 4, 2]);
+        assert_eq!(list.last(), Some(&2));
+        assert!(matches!(
+            List::<i32>::new().swap_remove(0),
+            Err(Errors::OutOfBounds)
+        ));
     }
 
     #[test]
-    fn insert_test() {
+    fn fill_test() {
         // Arrange
-        let mut list = List::new();
-        list.push_back(1);
-        list.push_back(3);
-        let _ = list.insert(1, 2);
+        let mut list = (0..4).collect::<List<_>>();
+        let mut empty = List::<i32>::new();
+
+        // Act
+        list.fill(7);
+        empty.fill(7);
 
         // Assert
-        assert_eq!(list.len(), 3);
-        assert_eq!(list.iter().count(), 3);
-        for (expected, real) in list.iter().zip(1..4) {
-            assert_eq!(expected, &real);
-        }
+        assert_eq!(list, [7, 7, 7, 7]);
+        assert_eq!(list.iter().rev().count(), 4);
+        assert!(empty.is_empty());
     }
 
     #[test]
-    fn insert_out_of_bound_test() {
+    fn fill_with_test() {
         // Arrange
-        let mut list = List::new();
-        list.push_back(1);
-        list.push_back(3);
-        let result = list.insert(3, 2);
+        let mut list = List::from([0; 4]);
+        let mut counter = 10;
 
-        assert!(result.is_err());
+        // Act
+        list.fill_with(|| {
+            counter += 1;
+            counter
+        });
+
+        // Assert
+        assert_eq!(list, [11, 12, 13, 14]);
+        assert_eq!(counter, 14);
     }
 
     #[test]
-    fn reverse_test() {
+    fn display_test() {
         // Arrange
-        let mut list = List::new();
-        list.extend(0..5);
-
-        // Assert
-        assert_eq!(list.len(), 5);
-        assert_eq!(list.iter().count(), 5);
-        for (expected, real) in list.iter().zip(0..5) {
-            assert_eq!(expected, &real);
-        }
+        let list = List::from([1, 2, 3]);
+        let single = List::from(["a"]);
+        let empty = List::<i32>::new();
 
         // Act
-        list.reverse();
+        let displayed = format!("{}", list);
+        let displayed_single = format!("{}", single);
+        let displayed_empty = format!("{}", empty);
 
         // Assert
-        assert_eq!(list.len(), 5);
-        assert_eq!(list.iter().count(), 5);
-        let mut other = (0..5).collect::<Vec<_>>();
-        other.reverse();
-        for (expected, real) in list.iter().zip(other) {
-            assert_eq!(expected, &real);
-        }
+        assert_eq!(displayed, "1, 2, 3");
+        assert_eq!(displayed_single, "a");
+        assert_eq!(displayed_empty, "");
+        assert_eq!(format!("{:?}", list), "[1, 2, 3]");
     }
 
     #[test]
-    fn clear_test() {
+    fn join_test() {
         // Arrange
-        let mut list = List::new();
-        list.extend(0..5);
-        list.clear();
+        let list = List::from([1, 2, 3]);
 
-        // assert
-        assert_eq!(list.len(), 0);
-        assert_eq!(list.iter().count(), 0);
+        // Act
+        let joined = list.join(" -> ");
+        let joined_empty = List::<i32>::new().join(" -> ");
+
+        // Assert
+        assert_eq!(joined, "1 -> 2 -> 3");
+        assert_eq!(joined_empty, "");
+        assert_eq!(list.join(", "), list.to_string());
     }
 
     #[test]
-    fn first_last_same_test() {
+    fn multiset_eq_test() {
         // Arrange
-        let mut list = List::new();
-        list.push_back(1);
+        let list = List::from([1, 2, 3]);
+        let shuffled = List::from([3, 1, 2]);
+        let ones = List::from([1, 1, 2]);
+        let twos = List::from([1, 2, 2]);
 
-        // assert
-        assert_eq!(list.first(), Some(&1));
-        assert_eq!(list.last(), Some(&1));
-        assert_eq!(list.iter().count(), 1);
+        // Act
+        let same = list.multiset_eq(&shuffled);
+        let different_counts = ones.multiset_eq(&twos);
+        let different_len = list.multiset_eq(&List::from([1, 2]));
+
+        // Assert
+        assert!(same);
+        assert!(!different_counts);
+        assert!(!different_len);
+        assert!(List::<i32>::new().multiset_eq(&List::new()));
+        // Neither list is reordered
+        assert_eq!(shuffled, [3, 1, 2]);
     }
 
     #[test]
-    fn first_last_different_test() {
+    fn first_or_push_front_with_test() {
         // Arrange
-        let mut list = List::new();
-        list.push_back(1);
-        list.push_back(2);
+        let mut empty = List::new();
+        let mut list = List::from([1, 2]);
+        let mut calls = 0;
 
-        // assert
-        assert_eq!(list.first(), Some(&1));
-        assert_eq!(list.last(), Some(&2));
-        assert_eq!(list.iter().count(), 2);
+        // Act
+        *empty.first_or_push_front_with(|| {
+            calls += 1;
+            5
+        }) += 1;
+        *list.first_or_push_front_with(|| {
+            calls += 1;
+            5
+        }) += 10;
+
+        // Assert
+        assert_eq!(calls, 1);
+        assert_eq!(empty, [6]);
+        assert_eq!(empty.last(), Some(&6));
+        assert_eq!(list, [11, 2]);
     }
 
     #[test]
-    fn first_last_empty_test() {
+    fn last_or_push_back_with_test() {
         // Arrange
-        let list = List::<i32>::new();
+        let mut empty = List::new();
+        let mut list = List::from([1, 2]);
+        let mut calls = 0;
 
-        // assert
-        assert_eq!(list.first(), None);
-        assert_eq!(list.last(), None);
-        assert_eq!(list.iter().count(), 0);
+        // Act
+        *empty.last_or_push_back_with(|| {
+            calls += 1;
+            5
+        }) += 1;
+        *list.last_or_push_back_with(|| {
+            calls += 1;
+            5
+        }) += 10;
+
+        // Assert
+        assert_eq!(calls, 1);
+        assert_eq!(empty, [6]);
+        assert_eq!(empty.first(), Some(&6));
+        assert_eq!(list, [1, 12]);
     }
 
     #[test]
-    fn from_iterator_test() {
+    fn try_get_test() {
         // Arrange
-        let list = (0..5).collect::<List<_>>();
+        let mut list = (0..3).collect::<List<_>>();
 
-        // assert
-        assert_eq!(list.first(), Some(&0));
-        assert_eq!(list.last(), Some(&4));
-        assert_eq!(list.iter().count(), 5);
-        assert_eq!(list.len(), 5);
+        // Act
+        if let Ok(value) = list.try_get_mut(2) {
+            *value = 20;
+        }
+
+        // Assert
+        assert!(matches!(list.try_get(0), Ok(&0)));
+        assert!(matches!(list.try_get(2), Ok(&20)));
+        assert!(matches!(list.try_get(3), Err(Errors::OutOfBounds)));
+        assert!(matches!(list.try_get_mut(3), Err(Errors::OutOfBounds)));
+        assert!(matches!(
+            List::<i32>::new().try_get(0),
+            Err(Errors::OutOfBounds)
+        ));
     }
 
     #[test]
-    fn clone_add_test() {
+    fn try_get_question_mark_test() {
         // Arrange
-        let mut list = List::new();
-        list.push_back(0);
-        list.push_back(1);
-        let other_list = list.clone();
-        list.push_back(2);
+        fn sum_ends(list: &List<i32>, last: usize) -> Result<i32, Errors> {
+            Ok(list.try_get(0)? + list.try_get(last)?)
+        }
+        let list = List::from([1, 2, 3]);
 
-        // assert
-        assert_eq!(list.first(), other_list.first());
-        assert_ne!(list.last(), other_list.last());
-        assert_eq!(list.len(), other_list.len() + 1);
-        assert_eq!(list.iter().count(), other_list.iter().count() + 1);
+        // Assert
+        assert!(matches!(sum_ends(&list, 2), Ok(4)));
+        assert!(matches!(sum_ends(&list, 3), Err(Errors::OutOfBounds)));
     }
 
     #[test]
-    fn partial_eq_test() {
+    fn clone_from_test() {
         // Arrange
-        let list1 = (0..5).collect::<List<_>>();
-        let list2 = (0..5).collect::<List<_>>();
+        let source = (10..13).collect::<List<_>>();
+        let mut list = (0..5).collect::<List<_>>();
+        let mut empty = List::new();
+        list.set_recycle_limit(8);
+
+        // Act
+        list.clone_from(&source);
+        empty.clone_from(&source);
 
         // Assert
-        assert_eq!(list1, list2);
+        assert_eq!(list, source);
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![12, 11, 10]
+        );
+        // Five nodes went to the pool, three of them were reused
+        assert_eq!(list.recycled.len(), 2);
+        assert_eq!(list.recycle_limit, 8);
+        assert_eq!(empty, source);
     }
 
     #[test]
-    fn partial_eq_not_same_len_test() {
+    fn clone_from_no_leak_test() {
         // Arrange
-        let list1 = (0..5).collect::<List<_>>();
-        let list2 = (0..4).collect::<List<_>>();
+        let old = std::rc::Rc::new(0);
+        let new = std::rc::Rc::new(1);
+        let mut list = (0..4)
+            .map(|_| std::rc::Rc::clone(&old))
+            .collect::<List<_>>();
+        let source = (0..2)
+            .map(|_| std::rc::Rc::clone(&new))
+            .collect::<List<_>>();
+        list.set_recycle_limit(4);
+
+        // Act
+        list.clone_from(&source);
 
         // Assert
-        assert_ne!(list1, list2);
+        assert_eq!(std::rc::Rc::strong_count(&old), 1);
+        assert_eq!(std::rc::Rc::strong_count(&new), 5);
+        drop(list);
+        drop(source);
+        assert_eq!(std::rc::Rc::strong_count(&new), 1);
     }
 
     #[test]
-    fn partial_eq_not_same_value_test() {
+    fn clone_into_test() {
         // Arrange
-        let list1 = (0..5).collect::<List<_>>();
-        let list2 = (1..6).collect::<List<_>>();
+        let source = List::from([String::from("a"), String::from("b")]);
+        let mut list = List::from([String::from("c")]);
+
+        // Act
+        source.clone_into(&mut list);
 
         // Assert
-        assert_ne!(list1, list2);
+        assert_eq!(list, source);
     }
 
     #[test]
-    fn fmt_test() {
+    fn dedup_by_test() {
         // Arrange
-        let list = (0..2).collect::<List<_>>();
-
-        // Convert the debug representation to a string
-        let debug_output = format!("{:?}", list);
+        let mut list = List::from([("a", 1), ("a", 2), ("b", 3), ("a", 4), ("a", 5), ("a", 6)]);
+
+        // Act (the counts of each run are summed into its first element)
+        list.dedup_by(|current, kept| {
+            if current.0 == kept.0 {
+                kept.1 += current.1;
+                true
+            } else {
+                false
+            }
+        });
 
         // Assert
-        assert_eq!("[0, 1]", debug_output);
+        assert_eq!(list, [("a", 3), ("b", 3), ("a", 15)]);
+        assert_eq!(list.last(), Some(&("a", 15)));
+        assert_eq!(list.iter().rev().count(), 3);
     }
 
     #[test]
-    fn get_index_test() {
+    fn dedup_by_no_leak_test() {
         // Arrange
-        let list = (0..2).collect::<List<_>>();
+        let item = std::rc::Rc::new(0);
+        let mut list = (0..4)
+            .map(|_| std::rc::Rc::clone(&item))
+            .collect::<List<_>>();
+        let mut empty = List::<i32>::new();
+
+        // Act
+        list.dedup_by(|current, kept| std::rc::Rc::ptr_eq(current, kept));
+        empty.dedup_by(|_, _| true);
 
         // Assert
-        assert_eq!(0, list[0]);
-        assert_eq!(Some(&0), list.get(0));
-        assert_eq!(1, list[1]);
-        assert_eq!(Some(&1), list.get(1));
-        assert_eq!(None, list.get(2));
+        assert_eq!(list.len(), 1);
+        assert_eq!(std::rc::Rc::strong_count(&item), 2);
+        assert!(empty.is_empty());
     }
 
     #[test]
-    fn get_mut_test() {
+    fn reverse_range_test() {
         // Arrange
-        let mut list = (0..2).collect::<List<_>>();
+        let mut list = (0..6).collect::<List<_>>();
 
-        // Act & Assert
-        list[0] = 1;
-        assert_eq!(1, list[0]);
-        list.get_mut(1).map(|v| *v += 1);
-        assert_eq!(2, list[1]);
+        // Act
+        let result = list.reverse_range(2..5);
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(list, [0, 1, 4, 3, 2, 5]);
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![5, 2, 3, 4, 1, 0]
+        );
+        assert_eq!((list.first(), list.last()), (Some(&0), Some(&5)));
     }
 
     #[test]
-    #[should_panic]
-    fn index_panic_out_of_bounds_test() {
+    fn reverse_range_full_test() {
         // Arrange
-        let list = (0..2).collect::<List<_>>();
+        let mut list = (0..6).collect::<List<_>>();
+        let mut expected = list.clone();
+        let mut prefix = list.clone();
+
+        // Act
+        list.reverse_range(..).unwrap();
+        expected.reverse();
+        prefix.reverse_range(..=2).unwrap();
 
         // Assert
-        list[2];
+        assert_eq!(list, expected);
+        assert_eq!((list.first(), list.last()), (Some(&5), Some(&0)));
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4, 5]
+        );
+        assert_eq!(prefix, [2, 1, 0, 3, 4, 5]);
+        assert_eq!(prefix.first(), Some(&2));
     }
 
     #[test]
-    #[should_panic]
-    fn index_mut_panic_out_of_bounds_test() {
+    fn reverse_range_out_of_bounds_test() {
         // Arrange
-        let mut list = (0..2).collect::<List<_>>();
+        let mut list = (0..3).collect::<List<_>>();
+
+        // Act
+        let too_far = list.reverse_range(1..4);
+        let past_end = list.reverse_range(4..);
+        let single = list.reverse_range(1..2);
+        let empty = list.reverse_range(3..);
 
         // Assert
-        list[2] = 2;
+        assert!(matches!(too_far, Err(Errors::OutOfBounds)));
+        assert!(matches!(past_end, Err(Errors::OutOfBounds)));
+        assert!(single.is_ok());
+        assert!(empty.is_ok());
+        assert_eq!(list, [0, 1, 2]);
     }
 
     #[test]
-    fn index_get_from_end_test() {
+    fn swap_ends_test() {
         // Arrange
-        let list = (0..5).collect::<List<_>>();
+        let mut empty = List::<i32>::new();
+        let mut single = List::from([1]);
+        let mut pair = List::from([1, 2]);
+        let mut list = (0..5).collect::<List<_>>();
+
+        // Act
+        empty.swap_ends();
+        single.swap_ends();
+        pair.swap_ends();
+        list.swap_ends();
 
         // Assert
-        assert_eq!(list.get(3), Some(&3));
+        assert!(empty.is_empty());
+        assert_eq!(single, [1]);
+        assert_eq!(pair, [2, 1]);
+        assert_eq!(list, [4, 1, 2, 3, 0]);
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![0, 3, 2, 1, 4]
+        );
     }
 
     #[test]
-    fn reverse_empty_test() {
+    fn aggregate_chunks_huge_size_test() {
         // Arrange
-        let mut list = List::<i32>::new();
-        list.reverse();
+        let list = (1..4).collect::<List<_>>();
+
+        // Act
+        let sums = list.aggregate_chunks(usize::MAX, |chunk| chunk.iter().copied().sum::<i32>());
+        let sizes = list.aggregate_chunks(1 << 40, |chunk| chunk.len());
 
         // Assert
-        assert!(list.is_empty());
+        assert_eq!(sums, [6]);
+        assert_eq!(sizes, [3]);
     }
 
     #[test]
-    fn concatenate_left_empty_test() {
+    fn windows_huge_size_test() {
         // Arrange
-        let mut list = List::<i32>::new();
-        let other_list = (0..5).collect::<List<_>>();
-        let clone = other_list.clone();
-        list.concatenate(other_list);
+        let list = (0..3).collect::<List<_>>();
+
+        // Act
+        let mut huge = list.windows(usize::MAX);
+        let mut large = list.windows(1 << 40);
 
         // Assert
-        assert_eq!(list, clone);
+        assert_eq!(huge.next(), None);
+        assert_eq!(large.next(), None);
+        assert_eq!(list.windows(3).count(), 1);
     }
 
     #[test]
-    fn concatenate_right_empty_test() {
+    fn append_len_overflow_test() {
         // Arrange
-        let mut list = (0..5).collect::<List<_>>();
-        let other_list = List::<i32>::new();
-        let clone = list.clone();
-        list.concatenate(other_list);
+        let item = std::rc::Rc::new(0);
+        let mut list = List::from([std::rc::Rc::clone(&item)]);
+        let mut other = List::from([std::rc::Rc::clone(&item)]);
+        list.len = usize::MAX;
 
-        // Assert
-        assert_eq!(list, clone);
+        // Act
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            list.append(&mut other);
+        }));
+        list.len = 1;
+
+        // Assert (each list still owns only its own node)
+        assert!(result.is_err());
+        assert_eq!(list.iter().count(), 1);
+        assert_eq!(other.iter().count(), 1);
+        assert_eq!(list.last(), list.first());
+        drop(list);
+        drop(other);
+        assert_eq!(std::rc::Rc::strong_count(&item), 1);
     }
 
     #[test]
-    fn concatenate_test() {
+    fn splice_at_len_overflow_test() {
         // Arrange
-        let mut list = (0..3).collect::<List<_>>();
-        let other_list = (3..5).collect::<List<_>>();
-        list.concatenate(other_list);
+        let item = std::rc::Rc::new(0);
+        let mut list = List::from([std::rc::Rc::clone(&item), std::rc::Rc::clone(&item)]);
+        let other = List::from([std::rc::Rc::clone(&item)]);
+        list.len = usize::MAX;
+
+        // Act (other is dropped while unwinding, list keeps its nodes)
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _ = list.splice_at(1, other);
+        }));
+        list.len = 2;
 
         // Assert
-        assert_eq!(list, (0..5).collect::<List<_>>());
+        assert!(result.is_err());
+        assert_eq!(list.iter().count(), 2);
+        assert_eq!(list.iter().rev().count(), 2);
+        assert_eq!(std::rc::Rc::strong_count(&item), 3);
+        drop(list);
+        assert_eq!(std::rc::Rc::strong_count(&item), 1);
     }
 
     #[test]
-    fn sort_by_test() {
+    fn repeat_empty_huge_test() {
         // Arrange
-        let mut list = (0..3).collect::<List<_>>();
-        let mut list2 = (3..8).collect::<List<_>>();
-        list2.reverse();
-        list.concatenate(list2);
+        let empty = List::<i32>::new();
 
-        list.sort_by(|a, b| b.cmp(a));
+        // Act
+        let repeated = empty.repeat(usize::MAX);
 
         // Assert
-        assert!(list.iter().is_sorted_by(|a, b| a > b));
-        assert_eq!(list, (0..8).rev().collect::<List<_>>());
+        assert!(repeated.is_empty());
     }
 
     #[test]
-    fn sort_test() {
+    #[should_panic(expected = "list length overflow")]
+    fn repeat_len_overflow_test() {
         // Arrange
-        let mut list = (0..3).collect::<List<_>>();
-        let mut list2 = (3..8).collect::<List<_>>();
-        list2.reverse();
-        list.concatenate(list2);
-
-        list.sort();
+        let list = List::from([1, 2]);
 
-        // Assert
-        assert!(list.iter().is_sorted_by(|a, b| a < b));
-        assert_eq!(list, (0..8).collect::<List<_>>());
+        // Act
+        list.repeat(usize::MAX);
     }
 
     #[test]
-    fn sort_empty_test() {
+    fn set_max_pooled_large_clear_test() {
         // Arrange
-        let mut list = (0..1).collect::<List<_>>();
-        list.sort();
+        let mut list = (0..10_000).collect::<List<_>>();
+        list.set_max_pooled(64);
+
+        // Act (a burst of removals through every freeing path)
+        list.truncate(5_000);
+        let after_truncate = list.recycled.len();
+        while list.len() > 2_000 {
+            list.pop_front();
+            list.pop_back();
+        }
+        list.clear();
 
         // Assert
-        assert_eq!(list.len(), 1);
-        assert_eq!(list, (0..1).collect::<List<_>>());
+        assert_eq!(after_truncate, 64);
+        assert_eq!(list.recycled.len(), 64);
+        assert!(list.is_empty());
+
+        // Lowering the cap frees the surplus right away
+        list.set_max_pooled(8);
+        assert_eq!(list.recycled.len(), 8);
     }
 }
@@ -22,6 +22,25 @@ impl<T> Iterator for IntoIter<T> {
         // We need to implement pop_front first
         self.list.pop_front()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // The inner list knows exactly how many items are left
+        let remaining = self.list.len();
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // Pop from the back of the list
+        self.list.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {
+    fn len(&self) -> usize {
+        self.list.len()
+    }
 }
 
 #[cfg(test)]
@@ -40,4 +59,79 @@ pub mod tests {
         assert_eq!(iterator.next(), Some(2));
         assert_eq!(iterator.next(), None);
     }
+
+    #[test]
+    fn into_iter_size_hint_test() {
+        // Arrange
+        let list = (0..3).collect::<List<_>>();
+        let mut iterator = list.into_iter();
+
+        // Assert
+        assert_eq!(iterator.size_hint(), (3, Some(3)));
+        iterator.next();
+        assert_eq!(iterator.size_hint(), (2, Some(2)));
+        iterator.by_ref().for_each(drop);
+        assert_eq!(iterator.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn into_iter_partial_drop_test() {
+        // Arrange
+        let list = (0..3).map(Box::new).collect::<List<_>>();
+        let mut iterator = list.into_iter();
+
+        // Act
+        let first = iterator.next();
+        drop(iterator);
+
+        // Assert (remaining boxes are freed with the iterator, checked by Miri)
+        assert_eq!(first, Some(Box::new(0)));
+    }
+
+    #[test]
+    fn into_iter_partial_drop_releases_values_test() {
+        // Arrange
+        let shared = std::rc::Rc::new(());
+        let list = (0..3)
+            .map(|_| std::rc::Rc::clone(&shared))
+            .collect::<List<_>>();
+        let mut iterator = list.into_iter();
+
+        // Act
+        let first = iterator.next();
+        drop(iterator);
+
+        // Assert
+        assert_eq!(std::rc::Rc::strong_count(&shared), 2);
+        drop(first);
+        assert_eq!(std::rc::Rc::strong_count(&shared), 1);
+    }
+
+    #[test]
+    fn into_iter_double_ended_test() {
+        // Arrange
+        let list = (0..5).collect::<List<_>>();
+        let mut iterator = list.into_iter();
+
+        // Assert
+        assert_eq!(iterator.len(), 5);
+        assert_eq!(iterator.next(), Some(0));
+        assert_eq!(iterator.next_back(), Some(4));
+        assert_eq!(iterator.next_back(), Some(3));
+        assert_eq!(iterator.len(), 2);
+        assert_eq!(iterator.next(), Some(1));
+        assert_eq!(iterator.next_back(), Some(2));
+        assert_eq!(iterator.len(), 0);
+        assert_eq!(iterator.next(), None);
+        assert_eq!(iterator.next_back(), None);
+    }
+
+    #[test]
+    fn into_iter_rev_test() {
+        // Arrange
+        let list = (0..3).collect::<List<_>>();
+
+        // Assert
+        assert_eq!(list.into_iter().rev().collect::<Vec<_>>(), vec![2, 1, 0]);
+    }
 }
@@ -6,3 +6,59 @@ pub(crate) struct DoubleLinkedListItem<T> {
 }
 
 pub(crate) type ItemPtr<T> = *mut DoubleLinkedListItem<T>;
+
+/// A node detached from a `List`, owning its value and its allocation.
+///
+/// It is produced by `List::take_first` or `List::take_last` and can be put
+/// back into any list with `List::push_front_node` or `List::push_back_node`,
+/// which reuses the allocation instead of freeing and allocating a new node.
+/// The node stays opaque: its value is only reachable through its methods.
+///
+/// # Examples
+///
+/// ```
+/// # use raw_double_linked_list::prelude::{DetachedNode, List};
+/// let mut list = List::from([1, 2, 3]);
+///
+/// // Move the two first nodes to the back, in reverse order
+/// let mut held: Vec<DetachedNode<i32>> = Vec::new();
+/// held.extend(list.take_first());
+/// held.extend(list.take_first());
+/// while let Some(node) = held.pop() {
+///     list.push_back_node(node);
+/// }
+///
+/// assert_eq!(list, List::from([3, 2, 1]));
+/// ```
+pub struct DetachedNode<T> {
+    pub(crate) node: Box<DoubleLinkedListItem<T>>,
+}
+
+// SAFETY: a detached node has no links, it only owns its `T` value.
+unsafe impl<T: Send> Send for DetachedNode<T> {}
+unsafe impl<T: Sync> Sync for DetachedNode<T> {}
+
+impl<T> DetachedNode<T> {
+    /// Returns a reference to the value held by the node.
+    pub fn value(&self) -> &T {
+        &self.node.value
+    }
+
+    /// Returns a mutable reference to the value held by the node.
+    pub fn value_mut(&mut self) -> &mut T {
+        &mut self.node.value
+    }
+
+    /// Consumes the node and returns its value, freeing the allocation.
+    pub fn into_value(self) -> T {
+        self.node.value
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for DetachedNode<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("DetachedNode")
+            .field(&self.node.value)
+            .finish()
+    }
+}
@@ -1,11 +1,28 @@
+use std::collections::VecDeque;
+
+use super::linked_list::List;
 use super::list_item::ItemPtr;
 
 pub struct ListIter<'a, T> {
     pub(crate) left: Option<ItemPtr<T>>,
     pub(crate) right: Option<ItemPtr<T>>,
+    pub(crate) remaining: usize,
     pub(crate) _phantom: std::marker::PhantomData<&'a T>,
 }
 
+// SAFETY: the iterator only hands out `&T`, it behaves like `&'a T`.
+unsafe impl<T: Sync> Send for ListIter<'_, T> {}
+unsafe impl<T: Sync> Sync for ListIter<'_, T> {}
+
+// Implemented by hand since deriving would require `T: Clone`
+impl<T> Clone for ListIter<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ListIter<'_, T> {}
+
 impl<'a, T> Iterator for ListIter<'a, T> {
     type Item = &'a T;
 
@@ -24,12 +41,19 @@ impl<'a, T> Iterator for ListIter<'a, T> {
                     self.left = unsafe { (*ptr).next };
                 }
 
+                // One less element to yield
+                self.remaining -= 1;
+
                 // Return the value
                 Some(value)
             }
             None => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
 }
 
 impl<T> DoubleEndedIterator for ListIter<'_, T> {
@@ -48,10 +72,289 @@ impl<T> DoubleEndedIterator for ListIter<'_, T> {
                     self.right = unsafe { (*ptr).previous };
                 }
 
+                // One less element to yield
+                self.remaining -= 1;
+
+                // Return the value
+                Some(value)
+            }
+            None => None,
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for ListIter<'_, T> {}
+
+pub struct IndexedIter<'a, T> {
+    pub(crate) iter: ListIter<'a, T>,
+    pub(crate) front: usize,
+}
+
+impl<'a, T> Iterator for IndexedIter<'a, T> {
+    type Item = (usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.iter.next()?;
+        let index = self.front;
+        self.front += 1;
+        Some((index, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for IndexedIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let value = self.iter.next_back()?;
+        // The elements still to yield sit between `front` and this one
+        Some((self.front + self.iter.len(), value))
+    }
+}
+
+impl<T> ExactSizeIterator for IndexedIter<'_, T> {}
+
+pub struct ListIterMut<'a, T> {
+    pub(crate) left: Option<ItemPtr<T>>,
+    pub(crate) right: Option<ItemPtr<T>>,
+    pub(crate) remaining: usize,
+    pub(crate) _phantom: std::marker::PhantomData<&'a mut T>,
+}
+
+// SAFETY: the iterator hands out disjoint `&mut T`, it behaves like `&'a mut T`.
+unsafe impl<T: Send> Send for ListIterMut<'_, T> {}
+unsafe impl<T: Sync> Sync for ListIterMut<'_, T> {}
+
+impl<'a, T> ListIterMut<'a, T> {
+    /// Downgrades this iterator into a shared iterator.
+    ///
+    /// The returned iterator yields the elements this one hasn't yielded yet,
+    /// from both ends.
+    pub fn into_iter_ref(self) -> ListIter<'a, T> {
+        ListIter {
+            left: self.left,
+            right: self.right,
+            remaining: self.remaining,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Iterator for ListIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.left {
+            Some(ptr) => {
+                // Get a mutable reference to the current value
+                let value = unsafe { &mut (*ptr).value };
+
+                // Ensure left isn't equal to right
+                if self.left == self.right {
+                    self.left = None;
+                    self.right = None;
+                } else {
+                    // Update current to the next node
+                    self.left = unsafe { (*ptr).next };
+                }
+
+                // One less element to yield
+                self.remaining -= 1;
+
                 // Return the value
                 Some(value)
             }
             None => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for ListIterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.right {
+            Some(ptr) => {
+                // Get a mutable reference to the current value
+                let value = unsafe { &mut (*ptr).value };
+
+                // Ensure left isn't equal to right
+                if self.left == self.right {
+                    self.left = None;
+                    self.right = None;
+                } else {
+                    // Update current to the previous node
+                    self.right = unsafe { (*ptr).previous };
+                }
+
+                // One less element to yield
+                self.remaining -= 1;
+
+                // Return the value
+                Some(value)
+            }
+            None => None,
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for ListIterMut<'_, T> {}
+
+pub struct Drain<'a, T> {
+    pub(crate) list: &'a mut List<T>,
+    pub(crate) current: Option<ItemPtr<T>>,
+    pub(crate) remaining: usize,
+}
+
+// SAFETY: the drain only points into the list it mutably borrows,
+// it behaves like `&'a mut List<T>`.
+unsafe impl<T: Send> Send for Drain<'_, T> {}
+unsafe impl<T: Sync> Sync for Drain<'_, T> {}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let ptr = self.current?;
+
+        // Move forward before the node is freed
+        self.current = unsafe { (*ptr).next };
+        self.remaining -= 1;
+
+        // Unlinking keeps the list consistent after every step
+        Some(self.list._unlink_node(ptr))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> Drop for Drain<'_, T> {
+    fn drop(&mut self) {
+        // Remove the elements of the range that weren't iterated
+        for _ in self.by_ref() {}
+    }
+}
+
+pub struct Chunks<'a, T> {
+    pub(crate) iter: ListIter<'a, T>,
+    pub(crate) size: usize,
+}
+
+impl<'a, T> Iterator for Chunks<'a, T> {
+    type Item = Vec<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iter.len() == 0 {
+            return None;
+        }
+
+        // The last chunk takes whatever is left
+        Some(self.iter.by_ref().take(self.size).collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let chunks = self.iter.len().div_ceil(self.size);
+        (chunks, Some(chunks))
+    }
+}
+
+impl<T> ExactSizeIterator for Chunks<'_, T> {}
+
+pub struct RChunks<'a, T> {
+    pub(crate) iter: ListIter<'a, T>,
+    pub(crate) size: usize,
+}
+
+impl<'a, T> Iterator for RChunks<'a, T> {
+    type Item = Vec<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iter.len() == 0 {
+            return None;
+        }
+
+        // Walk backward from the tail, then restore the list order
+        let mut chunk = self.iter.by_ref().rev().take(self.size).collect::<Vec<_>>();
+        chunk.reverse();
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let chunks = self.iter.len().div_ceil(self.size);
+        (chunks, Some(chunks))
+    }
+}
+
+impl<T> ExactSizeIterator for RChunks<'_, T> {}
+
+pub struct Windows<'a, T> {
+    pub(crate) window: VecDeque<ItemPtr<T>>,
+    pub(crate) next: Option<ItemPtr<T>>,
+    pub(crate) size: usize,
+    pub(crate) _phantom: std::marker::PhantomData<&'a T>,
+}
+
+// SAFETY: the iterator only hands out `&T`, it behaves like `&'a T`.
+unsafe impl<T: Sync> Send for Windows<'_, T> {}
+unsafe impl<T: Sync> Sync for Windows<'_, T> {}
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = Vec<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Fill the window up to its size, one node per step once full
+        while self.window.len() < self.size {
+            let ptr = self.next?;
+            self.window.push_back(ptr);
+            self.next = unsafe { (*ptr).next };
+        }
+
+        let values = self
+            .window
+            .iter()
+            .map(|&ptr| unsafe { &(*ptr).value })
+            .collect();
+
+        // Slide past the first node for the next window
+        self.window.pop_front();
+        Some(values)
+    }
+}
+
+pub struct GroupBy<'a, T, F> {
+    pub(crate) iter: ListIter<'a, T>,
+    pub(crate) pending: Option<&'a T>,
+    pub(crate) same: F,
+}
+
+impl<'a, T, F> Iterator for GroupBy<'a, T, F>
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    type Item = Vec<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // A group starts with the element that ended the previous one
+        let first = self.pending.take().or_else(|| self.iter.next())?;
+        let mut group = vec![first];
+
+        // Extend the group while adjacent elements belong together
+        for item in self.iter.by_ref() {
+            if (self.same)(group[group.len() - 1], item) {
+                group.push(item);
+            } else {
+                self.pending = Some(item);
+                break;
+            }
+        }
+        Some(group)
+    }
 }
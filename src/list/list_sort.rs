@@ -17,51 +17,84 @@ where
         };
     }
 
-    // Calculate the length of the first half
-    let mid_len = len / 2;
+    // Bottom-up: merge sorted runs of width 1, 2, 4, ... until a single run
+    // covers the whole chain, without any recursion
+    let mut head = start;
+    let mut tail = start;
+    let mut width = 1;
+    while width < len {
+        let mut new_head: ItemPtr<T> = std::ptr::null_mut();
+        let mut new_tail: ItemPtr<T> = std::ptr::null_mut();
+
+        // Merge each pair of consecutive runs of the current width
+        let mut remaining = Some(head);
+        while let Some(left_head) = remaining {
+            let (left_tail, right_head) = cut_run(left_head, width);
+            let (right_head, right_tail) = match right_head {
+                Some(right_head) => {
+                    let (right_tail, rest) = cut_run(right_head, width);
+                    remaining = rest;
+                    (right_head, right_tail)
+                }
+                None => {
+                    remaining = None;
+                    (std::ptr::null_mut(), std::ptr::null_mut())
+                }
+            };
+            let merged = merge(left_head, left_tail, right_head, right_tail, f);
+
+            // Chain the merged run after the previous ones
+            if new_tail.is_null() {
+                new_head = merged.new_start;
+            } else {
+                unsafe {
+                    (*new_tail).next = Some(merged.new_start);
+                    (*merged.new_start).previous = Some(new_tail);
+                }
+            }
+            new_tail = merged.new_end;
+        }
 
-    // Find the middle node and the last node of the first half
-    let mut middle = start;
-    for _ in 0..mid_len - 1 {
-        middle = unsafe { (*middle).next.unwrap() };
+        head = new_head;
+        tail = new_tail;
+        width *= 2;
     }
 
-    // Get the first node of the second half
-    let second_half = unsafe { (*middle).next.unwrap() };
+    UpdateListBound {
+        new_start: head,
+        new_end: tail,
+    }
+}
 
-    // Temporarily break the list into two separate lists
-    unsafe {
-        // Disconnect the two halves
-        if let Some(second) = (*middle).next {
-            (*second).previous = None;
+// Detaches the run of at most `width` nodes starting at `head`,
+// returning its last node and the first node after it
+fn cut_run<T>(head: ItemPtr<T>, width: usize) -> (ItemPtr<T>, Option<ItemPtr<T>>) {
+    let mut tail = head;
+    for _ in 1..width {
+        match unsafe { (*tail).next } {
+            Some(next) => tail = next,
+            None => break,
         }
-        (*middle).next = None;
     }
 
-    // Sort both halves
-    let left_bounds = merge_sort_by(start, mid_len, f);
-    let right_bounds = merge_sort_by(second_half, len - mid_len, f);
-
-    // Merge the sorted halves back together
-    merge(
-        left_bounds.new_start,
-        left_bounds.new_end,
-        right_bounds.new_start,
-        right_bounds.new_end,
-        f,
-    )
+    // Sever the link with the rest of the chain
+    let rest = unsafe { (*tail).next.take() };
+    if let Some(rest_head) = rest {
+        unsafe { (*rest_head).previous = None };
+    }
+    (tail, rest)
 }
 
 // Helper function to merge two sorted lists
-fn merge<F, T>(
+pub(crate) fn merge<F, T>(
     left_head: ItemPtr<T>,
     left_tail: ItemPtr<T>,
     right_head: ItemPtr<T>,
     right_tail: ItemPtr<T>,
-    f: F,
+    mut f: F,
 ) -> UpdateListBound<T>
 where
-    F: Fn(&T, &T) -> std::cmp::Ordering,
+    F: FnMut(&T, &T) -> std::cmp::Ordering,
 {
     // Handle empty list cases
     if left_head.is_null() {
@@ -78,8 +111,10 @@ where
     }
 
     // Create a dummy head to simplify the merging logic
-    let mut dummy = unsafe { std::mem::zeroed::<DoubleLinkedListItem<T>>() };
-    let dummy_ptr: ItemPtr<T> = &mut dummy;
+    // Only its `next` link is ever used, so the value can stay uninitialized
+    let mut dummy = std::mem::MaybeUninit::<DoubleLinkedListItem<T>>::uninit();
+    let dummy_ptr: ItemPtr<T> = dummy.as_mut_ptr();
+    unsafe { std::ptr::addr_of_mut!((*dummy_ptr).next).write(None) };
     let mut current = dummy_ptr;
 
     let mut left = left_head;
@@ -1,3 +1,5 @@
+use std::ops::{Bound, RangeBounds};
+
 use super::list_item::ItemPtr;
 
 #[derive(Debug, Copy, Clone)]
@@ -43,3 +45,30 @@ pub fn find_index_through<T>(
     // Some value was found
     Some(raw_ptr)
 }
+
+pub fn resolve_range<R: RangeBounds<usize>>(range: &R, len: usize) -> Option<(usize, usize)> {
+    // Lower bound is inclusive
+    let start = match range.start_bound() {
+        Bound::Included(&index) => index,
+        Bound::Excluded(&index) => index.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+
+    // Upper bound is exclusive
+    let end = match range.end_bound() {
+        Bound::Included(&index) => index.checked_add(1)?,
+        Bound::Excluded(&index) => index,
+        Bound::Unbounded => len,
+    };
+
+    // A valid range is ordered and fits in the list
+    if start > end || end > len {
+        return None;
+    }
+
+    Some((start, end))
+}
+
+pub fn checked_len_add(len: usize, additional: usize) -> usize {
+    len.checked_add(additional).expect("list length overflow")
+}
@@ -1,7 +1,10 @@
-mod errors;
+mod cursor;
+pub mod errors;
 pub mod linked_list;
-mod list_into_iter;
-mod list_item;
-mod list_iter;
+pub(crate) mod list_into_iter;
+pub(crate) mod list_item;
+pub(crate) mod list_iter;
 mod list_sort;
 mod list_utility;
+#[cfg(feature = "serde")]
+mod serde_impl;
@@ -0,0 +1,85 @@
+//! `serde` support for `List`, enabled with the `serde` feature.
+//!
+//! A list is serialized as a sequence of its elements in iteration order, and
+//! deserialized by pushing every element of a sequence to the back.
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+
+use super::linked_list::List;
+
+impl<T: Serialize> Serialize for List<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for item in self.iter() {
+            seq.serialize_element(item)?;
+        }
+        seq.end()
+    }
+}
+
+struct ListVisitor<T> {
+    _phantom: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for ListVisitor<T> {
+    type Value = List<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut list = List::new();
+        while let Some(item) = seq.next_element()? {
+            list.push_back(item);
+        }
+        Ok(list)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for List<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(ListVisitor {
+            _phantom: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::linked_list::*;
+
+    #[test]
+    fn serde_round_trip_test() {
+        // Arrange
+        let list = List::from([3, 1, 2]);
+
+        // Act
+        let json = serde_json::to_string(&list).unwrap();
+        let decoded: List<i32> = serde_json::from_str(&json).unwrap();
+
+        // Assert
+        assert_eq!(json, "[3,1,2]");
+        assert_eq!(decoded, list);
+        assert_eq!(decoded.last(), Some(&2));
+    }
+
+    #[test]
+    fn serde_empty_and_invalid_test() {
+        // Arrange
+        let empty = List::<String>::new();
+
+        // Act
+        let json = serde_json::to_string(&empty).unwrap();
+        let decoded: List<String> = serde_json::from_str(&json).unwrap();
+        let invalid = serde_json::from_str::<List<i32>>("{\"a\": 1}");
+
+        // Assert
+        assert_eq!(json, "[]");
+        assert!(decoded.is_empty());
+        assert!(invalid.is_err());
+    }
+}